@@ -1,16 +1,74 @@
-use std::collections::HashMap;
+use std::sync::Arc;
 
-use super::{
-    function::{Function, Param},
-    tstype::TsType,
-};
+use super::{function::Function, statement::Statement, tstype::{TsType, TypeParam}, value::Value};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    #[default]
+    Public,
+    Protected,
+    Private,
+}
+
+/// The modifiers in front of a class member or a constructor parameter.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Modifiers {
+    pub access: Access,
+    pub is_static: bool,
+    pub is_abstract: bool,
+    pub is_override: bool,
+    pub is_readonly: bool,
+}
+
+#[derive(Debug)]
+pub struct Attribute {
+    pub doc: Option<String>,
+    pub modifiers: Modifiers,
+    pub name: String,
+    pub is_optional: bool,
+    pub kind: Option<TsType>,
+    pub value: Option<Arc<Value>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MethodKind {
+    Method,
+    Get,
+    Set,
+}
+
+#[derive(Debug)]
+pub struct Method {
+    pub modifiers: Modifiers,
+    pub kind: MethodKind,
+    pub is_optional: bool,
+    pub function: Function,
+}
+
+/// A parameter of the constructor with a modifier, like `constructor(private name: string)`.
+#[derive(Debug)]
+pub struct ParamProperty {
+    pub name: String,
+    pub modifiers: Modifiers,
+}
+
+#[derive(Debug)]
+pub struct Constructor {
+    pub modifiers: Modifiers,
+    pub properties: Vec<ParamProperty>,
+    pub function: Function,
+}
 
 #[derive(Debug)]
 pub struct Class {
+    pub doc: Option<String>,
     pub name: String,
-    pub extends: Option<String>,
-    pub implements: Vec<String>,
-    pub attributes: Vec<Param>,
-    pub methods: Vec<Function>,
-    pub template_args: HashMap<String, Vec<TsType>>,
+    pub is_abstract: bool,
+    pub extends: Option<TsType>,
+    pub implements: Vec<TsType>,
+    pub attributes: Vec<Attribute>,
+    pub constructor: Option<Constructor>,
+    pub methods: Vec<Method>,
+    pub static_blocks: Vec<Vec<Statement>>,
+    pub template_args: Vec<TypeParam>,
 }
@@ -0,0 +1,23 @@
+use super::{
+    class::Class, enumeration::Enum, function::Function, statement::Statement, tstype::TsType,
+};
+
+/// Ambient declarations, `declare ...`, only describe what the host provides.
+#[derive(Debug)]
+pub enum Declaration {
+    Function(Function),
+    Variable {
+        name: String,
+        is_const: bool,
+        kind: Option<TsType>,
+    },
+    Class(Box<Class>),
+    Enum(Enum),
+    /// `declare module "name" { ... }` and `declare namespace Name { ... }`.
+    Module {
+        name: String,
+        statements: Vec<Statement>,
+    },
+    /// `declare global { ... }` extends the global scope from inside a module.
+    Global(Vec<Statement>),
+}
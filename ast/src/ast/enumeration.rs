@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use super::value::Value;
+
+#[derive(Debug)]
+pub struct EnumMember {
+    pub doc: Option<String>,
+    pub name: String,
+    /// The initializer, or the auto-incremented number for members without one.
+    pub value: Arc<Value>,
+}
+
+#[derive(Debug)]
+pub struct Enum {
+    pub doc: Option<String>,
+    pub name: String,
+    /// Members of a `const enum` get inlined, there is no object at runtime.
+    pub is_const: bool,
+    pub members: Vec<EnumMember>,
+}
@@ -1,20 +1,28 @@
-use std::collections::HashMap;
+use std::sync::Arc;
 
-use super::{statement::Statement, tstype::TsType, value::Value};
+use super::{pattern::Pattern, statement::Statement, tstype::{TsType, TypeParam}, value::Value};
 
 #[derive(Debug)]
 pub struct Param {
-    pub name: String,
-    pub kinds: Vec<TsType>,
-    pub default: Option<Value>,
+    pub doc: Option<String>,
+    pub pattern: Pattern,
+    /// A rest parameter, `...rest`, collects all remaining arguments.
+    pub is_rest: bool,
+    pub is_optional: bool,
+    pub kind: Option<TsType>,
+    pub default: Option<Arc<Value>>,
 }
 
 #[derive(Debug)]
 pub struct Function {
+    pub doc: Option<String>,
     pub name: Option<String>,
-    pub template_args: HashMap<String, Vec<TsType>>,
+    pub template_args: Vec<TypeParam>,
     pub is_async: bool,
+    pub is_generator: bool,
+    /// Overload signatures and ambient declarations have no body.
+    pub is_declaration: bool,
     pub params: Vec<Param>,
-    pub returns: Vec<TsType>,
+    pub returns: Option<TsType>,
     pub block: Vec<Statement>,
 }
@@ -1,9 +1,14 @@
-use super::function::{Function, Param};
+use super::{
+    function::{Function, Param},
+    tstype::{TsType, TypeParam},
+};
 
 #[derive(Debug)]
 pub struct Interface {
+    pub doc: Option<String>,
     pub name: String,
-    pub extends: Option<String>,
+    pub template_args: Vec<TypeParam>,
+    pub extends: Vec<TsType>,
     pub attributes: Vec<Param>,
     pub methods: Vec<Function>,
 }
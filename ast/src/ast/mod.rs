@@ -1,9 +1,14 @@
 pub mod class;
+pub mod declaration;
+pub mod enumeration;
 pub mod function;
 pub mod ifelse;
 pub mod interface;
 pub mod module;
+pub mod namespace;
+pub mod object;
 pub mod operation;
+pub mod pattern;
 pub mod repeat;
 pub mod statement;
 pub mod switch;
@@ -11,5 +16,6 @@ pub mod trycatch;
 pub mod tstype;
 pub mod typedefinition;
 pub mod value;
+pub mod variable;
 
 pub use module::Module;
@@ -10,13 +10,32 @@ pub enum ImportAlias {
 
 #[derive(Debug)]
 pub enum Import {
+    /// `import "m"`, only for the side effects of the module.
     Normal{path: String},
-    From{names: Vec<ImportAlias>, path: String}
+    /// `import x, * as ns from "m"` or `import x, { a, b as c } from "m"`.
+    From{
+        default: Option<String>,
+        namespace: Option<String>,
+        names: Vec<ImportAlias>,
+        path: String,
+    },
+    /// `import type { T } from "m"` and `import { type T } from "m"` only import types,
+    /// which do not exist at runtime.
+    Type{names: Vec<ImportAlias>, path: String},
+}
+
+/// Exports of another module, `export * from "m"` or `export { a as b } from "m"`.
+#[derive(Debug)]
+pub enum Reexport {
+    All { alias: Option<String>, path: String },
+    Names { names: Vec<ImportAlias>, path: String },
 }
 
 #[derive(Debug)]
 pub struct Module {
+    /// The exported name and the value it stands for, usually the local declaration.
     pub exports: HashMap<String, Value>,
+    pub reexports: Vec<Reexport>,
     pub imports: Vec<Import>,
     pub statements: Vec<Statement>,
 }
@@ -25,6 +44,7 @@ impl Module {
     pub fn new() -> Self {
         Self {
             exports: HashMap::new(),
+            reexports: Vec::new(),
             imports: Vec::new(),
             statements: Vec::new(),
         }
@@ -0,0 +1,12 @@
+use super::statement::Statement;
+
+/// `namespace A { ... }`, or the legacy `module A { ... }`. A dotted name like
+/// `namespace A.B {}` is parsed into nested namespaces.
+#[derive(Debug)]
+pub struct Namespace {
+    pub doc: Option<String>,
+    pub name: String,
+    pub statements: Vec<Statement>,
+    /// The names of the members declared with `export`.
+    pub exports: Vec<String>,
+}
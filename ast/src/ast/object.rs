@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use super::{function::Function, value::Value};
+
+#[derive(Debug)]
+pub enum PropertyKey {
+    Name(String),
+    Computed(Arc<Value>),
+}
+
+#[derive(Debug)]
+pub enum Property {
+    Value { key: PropertyKey, value: Arc<Value> },
+    Shorthand(String),
+    Method { key: PropertyKey, function: Function },
+    Spread(Arc<Value>),
+}
@@ -1,19 +1,30 @@
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum Operation {
     Add,
     Sub,
     Mul,
     Div,
     Mod,
+    Pow,
     And,
     Or,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+    UShr,
     Gt,
     Gte,
     Lt,
     Lte,
     Eq,
     Ne,
+    StrictEq,
+    StrictNe,
+    InstanceOf,
+    In,
 }
 
 impl From<&str> for Operation {
@@ -24,21 +35,56 @@ impl From<&str> for Operation {
             "*" => Self::Mul,
             "/" => Self::Div,
             "%" => Self::Mod,
+            "**" => Self::Pow,
             "&&" => Self::And,
             "||" => Self::Or,
+            "&" => Self::BitAnd,
+            "|" => Self::BitOr,
+            "^" => Self::BitXor,
+            "<<" => Self::Shl,
+            ">>" => Self::Shr,
+            ">>>" => Self::UShr,
             "<" => Self::Lt,
             "<=" => Self::Lte,
             ">" => Self::Gt,
             ">=" => Self::Gte,
             "==" => Self::Eq,
             "!=" => Self::Ne,
-            "===" => Self::Eq,
-            "!==" => Self::Ne,
+            "===" => Self::StrictEq,
+            "!==" => Self::StrictNe,
+            "instanceof" => Self::InstanceOf,
+            "in" => Self::In,
             _ => Self::Add,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum UnaryOperation {
+    Not,
+    BitNot,
+    Negative,
+    Positive,
+    TypeOf,
+    Void,
+    Delete,
+}
+
+impl From<&str> for UnaryOperation {
+    fn from(ch: &str) -> Self {
+        match ch {
+            "!" => Self::Not,
+            "~" => Self::BitNot,
+            "-" => Self::Negative,
+            "+" => Self::Positive,
+            "typeof" => Self::TypeOf,
+            "void" => Self::Void,
+            "delete" => Self::Delete,
+            _ => Self::Positive,
+        }
+    }
+}
+
 impl From<&AssignOperation> for Operation {
     fn from(o: &AssignOperation) -> Self {
         match o {
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use super::{object::PropertyKey, value::Value};
+
+/// The left side of a declaration, a parameter or a destructuring assignment.
+#[derive(Debug)]
+pub enum Pattern {
+    Name(String),
+    /// Any other assignable expression, e.g. `[this.a, obj.b] = pair`.
+    Target(Arc<Value>),
+    Array {
+        /// `None` marks a hole, like in `[, second]`.
+        elements: Vec<Option<PatternElement>>,
+        rest: Option<Box<Pattern>>,
+    },
+    Object {
+        properties: Vec<PatternProperty>,
+        rest: Option<Box<Pattern>>,
+    },
+}
+
+#[derive(Debug)]
+pub struct PatternElement {
+    pub pattern: Pattern,
+    pub default: Option<Arc<Value>>,
+}
+
+#[derive(Debug)]
+pub struct PatternProperty {
+    pub key: PropertyKey,
+    pub pattern: Pattern,
+    pub default: Option<Arc<Value>>,
+}
+
+impl Pattern {
+    /// The bound name, if the pattern is not destructuring anything.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Name(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// All names the pattern binds, e.g. `a`, `b` and `rest` for `[a, { b }, ...rest]`.
+    pub fn names(&self) -> Vec<&str> {
+        match self {
+            Self::Name(name) => vec![name],
+            Self::Target(_) => Vec::new(),
+            Self::Array { elements, rest } => elements
+                .iter()
+                .flatten()
+                .flat_map(|e| e.pattern.names())
+                .chain(rest.iter().flat_map(|r| r.names()))
+                .collect(),
+            Self::Object { properties, rest } => properties
+                .iter()
+                .flat_map(|p| p.pattern.names())
+                .chain(rest.iter().flat_map(|r| r.names()))
+                .collect(),
+        }
+    }
+}
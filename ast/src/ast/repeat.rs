@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use super::{statement::Statement, value::Value};
+use super::{pattern::Pattern, statement::Statement, value::Value};
 
 #[derive(Debug)]
 pub enum Loop {
@@ -8,6 +8,11 @@ pub enum Loop {
         cond: Arc<Value>,
         block: Vec<Statement>,
     },
+    /// `do {} while ()` runs the block once before checking `cond`.
+    DoWhile {
+        block: Vec<Statement>,
+        cond: Arc<Value>,
+    },
     For {
         init: Vec<Statement>,
         cond: Arc<Value>,
@@ -15,12 +20,12 @@ pub enum Loop {
         block: Vec<Statement>,
     },
     ForOf{
-        name: String,
+        pattern: Pattern,
         value: Arc<Value>,
         block: Vec<Statement>,
     },
     ForIn{
-        name: String,
+        pattern: Pattern,
         value: Arc<Value>,
         block: Vec<Statement>,
     },
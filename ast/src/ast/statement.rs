@@ -1,21 +1,38 @@
 use std::sync::Arc;
 
 use super::{
-    class::Class, function::Function, ifelse::IfElse, interface::Interface, repeat::Loop,
+    class::Class, declaration::Declaration, enumeration::Enum, function::Function,
+    ifelse::IfElse, interface::Interface, namespace::Namespace, repeat::Loop,
     switch::Switch, trycatch::TryCatch, typedefinition::TypeDefinition, value::Value,
+    variable::Declarator,
 };
 
 #[derive(Debug)]
 pub enum Statement {
-    Const { name: String, value: Arc<Value> },
-    Let { name: String, value: Arc<Value> },
+    Const(Vec<Declarator>),
+    Let(Vec<Declarator>),
+    /// Unlike `let`, the names are declared for the whole function, see
+    /// [`var_names`](super::variable::var_names).
+    Var(Vec<Declarator>),
     Expression(Arc<Value>),
+    Block(Vec<Statement>),
     If(IfElse),
     Switch(Switch),
     Loop(Loop),
+    /// `break`, optionally leaving the statement with the given label.
+    Break(Option<String>),
+    /// `continue`, optionally with the label of the loop to continue.
+    Continue(Option<String>),
+    Labeled {
+        label: String,
+        statement: Box<Statement>,
+    },
     Return(Arc<Value>),
     Function(Function),
-    Class(Class),
+    Class(Box<Class>),
+    Enum(Enum),
+    Declare(Declaration),
+    Namespace(Namespace),
     Interface(Interface),
     Type(TypeDefinition),
     TryCatch(TryCatch),
@@ -1,23 +1,144 @@
+use std::sync::Arc;
+
+use super::{function::Param, value::Value};
+
+/// A type parameter, like `T extends object = {}` in `<T extends object = {}>`.
+#[derive(Debug)]
+pub struct TypeParam {
+    pub name: String,
+    pub constraint: Option<TsType>,
+    pub default: Option<TsType>,
+}
+
+#[derive(Debug)]
+pub struct TupleElement {
+    pub name: Option<String>,
+    pub is_optional: bool,
+    pub is_rest: bool,
+    pub kind: TsType,
+}
+
+/// A member of an object type literal, like `{ name: string; [key: string]: unknown }`.
+#[derive(Debug)]
+pub enum TypeMember {
+    Property {
+        name: String,
+        is_optional: bool,
+        is_readonly: bool,
+        kind: Option<TsType>,
+    },
+    /// `kind` is always a [`TsType::Function`].
+    Method {
+        name: String,
+        is_optional: bool,
+        kind: TsType,
+    },
+    Index {
+        name: String,
+        is_readonly: bool,
+        key: TsType,
+        kind: TsType,
+    },
+    Call(TsType),
+    Construct(TsType),
+}
+
+/// `+readonly`/`-readonly` and `+?`/`-?` in mapped types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappedModifier {
+    Add,
+    Remove,
+}
 
 #[derive(Debug)]
 pub enum TsType {
     Any,
+    Unknown,
+    Never,
+    Void,
+    Undefined,
+    Null,
     Number,
     String,
     Boolean,
-    Null,
-    Custom(String),
+    BigInt,
+    Symbol,
+    Object,
+    This,
+    /// A string, number, boolean or bigint literal.
+    Literal(Arc<Value>),
+    Reference {
+        name: String,
+        args: Vec<TsType>,
+    },
+    Array(Box<TsType>),
+    Tuple(Vec<TupleElement>),
+    Function {
+        is_constructor: bool,
+        template_args: Vec<TypeParam>,
+        params: Vec<Param>,
+        returns: Box<TsType>,
+    },
+    TypeLiteral(Vec<TypeMember>),
+    Union(Vec<TsType>),
+    Intersection(Vec<TsType>),
+    KeyOf(Box<TsType>),
+    Readonly(Box<TsType>),
+    TypeOf {
+        path: Vec<String>,
+        args: Vec<TsType>,
+    },
+    Indexed {
+        object: Box<TsType>,
+        index: Box<TsType>,
+    },
+    Conditional {
+        check: Box<TsType>,
+        extends: Box<TsType>,
+        then: Box<TsType>,
+        otherwise: Box<TsType>,
+    },
+    Infer(String),
+    Mapped {
+        name: String,
+        constraint: Box<TsType>,
+        rename: Option<Box<TsType>>,
+        readonly: Option<MappedModifier>,
+        optional: Option<MappedModifier>,
+        kind: Option<Box<TsType>>,
+    },
+    TemplateLiteral {
+        quasis: Vec<String>,
+        kinds: Vec<TsType>,
+    },
+    /// Return types like `x is string` or `asserts x`.
+    Predicate {
+        name: String,
+        asserts: bool,
+        kind: Option<Box<TsType>>,
+    },
 }
 
 impl From<&str> for TsType {
     fn from(s: &str) -> Self {
         match s {
             "any" => Self::Any,
+            "unknown" => Self::Unknown,
+            "never" => Self::Never,
+            "void" => Self::Void,
+            "undefined" => Self::Undefined,
+            "null" => Self::Null,
             "number" => Self::Number,
             "string" => Self::String,
             "boolean" => Self::Boolean,
-            "null" => Self::Null,
-            _ => Self::Custom(s.to_string()),
+            "bigint" => Self::BigInt,
+            "symbol" => Self::Symbol,
+            "object" => Self::Object,
+            "this" => Self::This,
+            _ => Self::Reference {
+                name: s.to_string(),
+                args: Vec::new(),
+            },
         }
     }
 }
@@ -30,4 +151,4 @@ impl From<Option<&str>> for TsType {
             Self::Any
         }
     }
-}
\ No newline at end of file
+}
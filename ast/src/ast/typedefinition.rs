@@ -1,14 +1,10 @@
-use super::function::Param;
-
-
-#[derive(Debug)]
-pub struct TypeBlock {
-    pub attributes: Vec<Param>,
-}
+use super::tstype::{TsType, TypeParam};
 
+/// A type alias, like `type Pair<T> = [T, T]`.
 #[derive(Debug)]
 pub struct TypeDefinition {
+    pub doc: Option<String>,
     pub name: String,
-    pub blocks: Vec<TypeBlock>,
-    pub aggregates: Vec<String>,
-}
\ No newline at end of file
+    pub template_args: Vec<TypeParam>,
+    pub kind: TsType,
+}
@@ -1,28 +1,109 @@
 use std::sync::Arc;
 
-use super::operation::{AssignOperation, Operation};
+use super::{
+    function::Function,
+    object::Property,
+    operation::{AssignOperation, Operation, UnaryOperation},
+    pattern::Pattern,
+    tstype::TsType,
+};
 
 #[derive(Debug)]
 pub enum Value {
     Number(f64),
+    /// Decimal digits of a `123n` literal.
+    BigInt(String),
     String(String),
     Identifier(Vec<String>),
     Boolean(bool),
     Array(Vec<Arc<Value>>),
+    Object(Vec<Property>),
+    Template {
+        quasis: Vec<String>,
+        exprs: Vec<Arc<Value>>,
+    },
+    TaggedTemplate {
+        tag: Arc<Value>,
+        quasis: Vec<String>,
+        exprs: Vec<Arc<Value>>,
+    },
+    Member {
+        object: Arc<Value>,
+        property: String,
+        optional: bool,
+    },
+    Index {
+        object: Arc<Value>,
+        index: Arc<Value>,
+        optional: bool,
+    },
     Call {
-        identifier: Vec<String>,
+        callee: Arc<Value>,
         args: Vec<Arc<Value>>,
+        optional: bool,
     },
+    /// An optional chain like `a?.b.c`. The whole chain evaluates to `undefined` as soon as
+    /// one of its optional links hits a nullish value.
+    Chain(Arc<Value>),
     Null,
+    /// Arrow functions and function expressions.
+    Function(Function),
+    This,
+    Super,
+    New {
+        callee: Arc<Value>,
+        args: Vec<Arc<Value>>,
+    },
     Undefined,
     Expression {
         left: Arc<Value>,
         op: Operation,
         right: Arc<Value>,
     },
+    Conditional {
+        cond: Arc<Value>,
+        then: Arc<Value>,
+        els: Arc<Value>,
+    },
+    Nullish {
+        left: Arc<Value>,
+        right: Arc<Value>,
+    },
+    Unary {
+        op: UnaryOperation,
+        value: Arc<Value>,
+    },
+    Await(Arc<Value>),
+    /// `value as T` or `<T>value`, which only matters to a type checker.
+    As {
+        value: Arc<Value>,
+        kind: TsType,
+    },
+    /// `value satisfies T`
+    Satisfies {
+        value: Arc<Value>,
+        kind: TsType,
+    },
+    /// `value!` asserts that `value` is neither `null` nor `undefined`.
+    NonNull(Arc<Value>),
+    /// `import("m")`, loads a module at runtime.
+    DynamicImport(Arc<Value>),
+    /// `yield value`, or `yield* value` when delegating to another iterable.
+    Yield {
+        value: Option<Arc<Value>>,
+        delegate: bool,
+    },
+    /// Writes to an `Identifier`, `Member` or `Index` target.
     Assign {
-        identifier: String,
+        target: Arc<Value>,
         op: AssignOperation,
         value: Arc<Value>,
     },
+    /// `...value` within the arguments of a call or an array literal.
+    Spread(Arc<Value>),
+    /// An assignment to an array or object pattern, e.g. `[a, b] = [b, a]`.
+    Destructure {
+        pattern: Pattern,
+        value: Arc<Value>,
+    },
 }
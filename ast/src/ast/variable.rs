@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use super::{
+    ifelse::IfElse, pattern::Pattern, repeat::Loop, statement::Statement, tstype::TsType,
+    value::Value,
+};
+
+/// One `pattern: type = value` of a `const`, `let` or `var` declaration.
+#[derive(Debug)]
+pub struct Declarator {
+    pub pattern: Pattern,
+    pub kind: Option<TsType>,
+    pub value: Option<Arc<Value>>,
+}
+
+/// The names declared with `var` anywhere in `statements`, which belong to the whole
+/// function and not only to the block they are written in. Nested functions and
+/// classes have their own scope and are not looked into.
+pub fn var_names(statements: &[Statement]) -> Vec<&str> {
+    let mut names = Vec::new();
+
+    for stmnt in statements {
+        collect_var_names(stmnt, &mut names);
+    }
+
+    names
+}
+
+fn collect_var_names<'a>(stmnt: &'a Statement, names: &mut Vec<&'a str>) {
+    let mut blocks: Vec<&'a [Statement]> = Vec::new();
+
+    match stmnt {
+        Statement::Var(declarators) => {
+            for declarator in declarators {
+                for name in declarator.pattern.names() {
+                    if !names.contains(&name) {
+                        names.push(name);
+                    }
+                }
+            }
+        }
+        Statement::Block(block) => blocks.push(block),
+        Statement::If(IfElse {
+            block,
+            elseifs,
+            els,
+            ..
+        }) => {
+            blocks.push(block);
+            blocks.extend(elseifs.iter().map(|e| e.block.as_slice()));
+            blocks.push(els);
+        }
+        Statement::Switch(switch) => {
+            blocks.extend(switch.branches.iter().map(|c| c.block.as_slice()));
+            blocks.extend(switch.default.as_deref());
+        }
+        Statement::Loop(
+            Loop::While { block, .. }
+            | Loop::DoWhile { block, .. }
+            | Loop::ForOf { block, .. }
+            | Loop::ForIn { block, .. },
+        ) => blocks.push(block),
+        Statement::Loop(Loop::For { init, block, .. }) => {
+            blocks.push(init);
+            blocks.push(block);
+        }
+        Statement::Labeled { statement, .. } => collect_var_names(statement, names),
+        Statement::TryCatch(trycatch) => {
+            blocks.push(&trycatch.try_block);
+            blocks.push(&trycatch.catch_block);
+        }
+        _ => {}
+    }
+
+    for block in blocks {
+        for stmnt in block {
+            collect_var_names(stmnt, names);
+        }
+    }
+}
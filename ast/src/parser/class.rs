@@ -1,46 +1,176 @@
-use std::collections::HashMap;
-
 use pest::iterators::Pair;
 
-use crate::ast::{class::Class, statement::Statement};
+use crate::ast::{
+    class::{
+        Access, Attribute, Class, Constructor, Method, MethodKind, Modifiers, ParamProperty,
+    },
+    statement::Statement,
+};
+
+use super::{
+    comment::parse_doc_comment,
+    expression::parse_expression,
+    parse_function, parse_statements,
+    tstype::{parse_reference, parse_template_definition, parse_ts_type},
+    Rule,
+};
+
+pub fn parse_modifiers(modifiers: Pair<Rule>) -> Modifiers {
+    let mut result = Modifiers::default();
+
+    for modifier in modifiers.into_inner() {
+        match modifier.as_rule() {
+            Rule::Access => {
+                result.access = match modifier.as_str() {
+                    "private" => Access::Private,
+                    "protected" => Access::Protected,
+                    _ => Access::Public,
+                }
+            }
+            Rule::Static => result.is_static = true,
+            Rule::Abstract => result.is_abstract = true,
+            Rule::Override => result.is_override = true,
+            Rule::Readonly => result.is_readonly = true,
+            _ => {}
+        }
+    }
+
+    result
+}
+
+fn parse_attribute(attribute: Pair<Rule>) -> Attribute {
+    let mut doc = None;
+    let mut modifiers = Modifiers::default();
+    let mut name = String::new();
+    let mut is_optional = false;
+    let mut kind = None;
+    let mut value = None;
+
+    for part in attribute.into_inner() {
+        match part.as_rule() {
+            Rule::DocComment => doc = Some(parse_doc_comment(part)),
+            Rule::Modifiers => modifiers = parse_modifiers(part),
+            Rule::Name => name = part.as_str().into(),
+            Rule::Optional => is_optional = true,
+            Rule::TsType => kind = Some(parse_ts_type(part)),
+            Rule::Expression => value = Some(parse_expression(part)),
+            _ => {}
+        }
+    }
+
+    Attribute {
+        doc,
+        modifiers,
+        name,
+        is_optional,
+        kind,
+        value,
+    }
+}
 
-use super::{parse_function, Rule, function::{parse_template_definition, parse_param}};
+/// Collects the constructor parameters which also declare an attribute.
+fn parse_param_properties(method: Pair<Rule>) -> Vec<ParamProperty> {
+    let mut properties = Vec::new();
+
+    let definition = method
+        .into_inner()
+        .find(|p| p.as_rule() == Rule::FunctionDefinition);
+    let params = definition
+        .and_then(|d| d.into_inner().find(|p| p.as_rule() == Rule::ParamList));
+
+    for param in params.into_iter().flat_map(|p| p.into_inner()) {
+        let mut inner = param.into_inner().skip_while(|p| p.as_rule() == Rule::DocComment);
+        let modifiers = inner.next().unwrap();
+
+        if modifiers.clone().into_inner().next().is_none() {
+            continue;
+        }
+
+        if let Some(binding) = inner.find(|p| p.as_rule() == Rule::Binding) {
+            properties.push(ParamProperty {
+                name: binding.as_str().into(),
+                modifiers: parse_modifiers(modifiers),
+            });
+        }
+    }
+
+    properties
+}
 
 pub fn parse_class(stmnt: Pair<Rule>) -> Statement {
-    let mut inner = stmnt.into_inner();
+    let mut inner = stmnt.into_inner().peekable();
+    let mut doc = None;
 
+    if inner.peek().unwrap().as_rule() == Rule::DocComment {
+        doc = Some(parse_doc_comment(inner.next().unwrap()));
+    }
+
+    let is_abstract = inner.next_if(|p| p.as_rule() == Rule::Abstract).is_some();
     let name = inner.next().unwrap().as_str();
     let mut extends = None;
     let mut implements = Vec::new();
     let mut attributes = Vec::new();
+    let mut constructor = None;
     let mut methods = Vec::new();
-    let mut template_args = HashMap::new();
+    let mut static_blocks = Vec::new();
+    let mut template_args = Vec::new();
 
-    while let Some(block) = inner.next() {
+    for block in inner {
         match block.as_rule() {
             Rule::TemplateDefinition => {
-                for inner in block.into_inner() {
-                    let (name, args) = parse_template_definition(inner);
-                    template_args.insert(name, args);
-                }
+                template_args = parse_template_definition(block);
             }
             Rule::Extends => {
-                let inner = block.into_inner().next().unwrap();
-                extends = Some(inner.as_str().into());
+                extends = Some(parse_reference(block));
             }
             Rule::Implements => {
-                for name in block.into_inner() {
-                    implements.push(name.as_str().into());
-                }
+                implements = block.into_inner().map(parse_reference).collect();
             }
             Rule::ClassBody => {
                 for part in block.into_inner() {
                     match part.as_rule() {
                         Rule::Method => {
-                            methods.push(parse_function(part));
+                            let mut modifiers = Modifiers::default();
+                            let mut kind = MethodKind::Method;
+                            let mut is_optional = false;
+
+                            for p in part.clone().into_inner() {
+                                match p.as_rule() {
+                                    Rule::Modifiers => modifiers = parse_modifiers(p),
+                                    Rule::Accessor if p.as_str() == "get" => kind = MethodKind::Get,
+                                    Rule::Accessor => kind = MethodKind::Set,
+                                    Rule::Optional => is_optional = true,
+                                    _ => {}
+                                }
+                            }
+
+                            let function = parse_function(part.clone());
+
+                            if function.name.as_deref() == Some("constructor") {
+                                // overload signatures must not replace the implementation
+                                if function.is_declaration && constructor.is_some() {
+                                    continue;
+                                }
+
+                                constructor = Some(Constructor {
+                                    modifiers,
+                                    properties: parse_param_properties(part),
+                                    function,
+                                });
+                            } else {
+                                methods.push(Method {
+                                    modifiers,
+                                    kind,
+                                    is_optional,
+                                    function,
+                                });
+                            }
                         }
                         Rule::ClassAttribute => {
-                            attributes.push(parse_param(part.into_inner().next().unwrap()));
+                            attributes.push(parse_attribute(part));
+                        }
+                        Rule::StaticBlock => {
+                            static_blocks.push(parse_statements(part.into_inner().next().unwrap()));
                         }
                         _ => {}
                     }
@@ -51,12 +181,16 @@ pub fn parse_class(stmnt: Pair<Rule>) -> Statement {
     }
 
     let class = Class {
+        doc,
         name: name.to_string(),
+        is_abstract,
         extends,
         implements,
         attributes,
+        constructor,
         methods,
+        static_blocks,
         template_args,
     };
-    Statement::Class(class)
+    Statement::Class(Box::new(class))
 }
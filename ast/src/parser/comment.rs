@@ -0,0 +1,20 @@
+use pest::iterators::Pair;
+
+use super::Rule;
+
+/// Returns the text of a `/** ... */` comment, without the delimiters and the
+/// leading `*` of each line.
+pub fn parse_doc_comment(comment: Pair<Rule>) -> String {
+    let data = comment.as_str();
+    let body = &data[3..data.len() - 2];
+
+    body.lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').unwrap_or(line).trim()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
@@ -0,0 +1,94 @@
+use pest::iterators::Pair;
+
+use crate::ast::{declaration::Declaration, statement::Statement};
+
+use super::{
+    class::parse_class,
+    comment::parse_doc_comment,
+    enumeration::parse_enum,
+    function::parse_function,
+    interface::parse_interface,
+    literal::parse_string,
+    parse_type,
+    tstype::parse_ts_type,
+    Rule,
+};
+
+fn parse_ambient_body(body: Pair<Rule>) -> Vec<Statement> {
+    body.into_inner()
+        .filter_map(|part| match part.as_rule() {
+            Rule::Declare => Some(parse_declare(part)),
+            Rule::Ambient => Some(parse_ambient(part, None)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Parses something which is declared without `declare` in front, like everything inside
+/// of `declare module` or in `.d.ts` files. Interfaces and types stay what they are.
+pub fn parse_ambient(ambient: Pair<Rule>, doc: Option<String>) -> Statement {
+    let inner = ambient.into_inner().next().unwrap();
+
+    let declaration = match inner.as_rule() {
+        Rule::DeclareGlobal => {
+            Declaration::Global(parse_ambient_body(inner.into_inner().next().unwrap()))
+        }
+        Rule::DeclareModule => {
+            let mut parts = inner.into_inner();
+            let name = parts.next().unwrap();
+            let name = match name.as_rule() {
+                Rule::String => parse_string(name),
+                _ => name.as_str().into(),
+            };
+
+            Declaration::Module {
+                name,
+                statements: parse_ambient_body(parts.next().unwrap()),
+            }
+        }
+        Rule::DeclareVariable => {
+            let mut parts = inner.into_inner().peekable();
+            let is_const = parts.next_if(|p| p.as_rule() == Rule::DeclareConst).is_some();
+            let name = parts.next().unwrap().as_str().into();
+            let kind = parts.next().map(parse_ts_type);
+
+            Declaration::Variable {
+                name,
+                is_const,
+                kind,
+            }
+        }
+        Rule::Function => {
+            let mut function = parse_function(inner);
+            function.doc = function.doc.or(doc);
+            Declaration::Function(function)
+        }
+        Rule::Class => match parse_class(inner) {
+            Statement::Class(mut class) => {
+                class.doc = class.doc.or(doc);
+                Declaration::Class(class)
+            }
+            s => return s,
+        },
+        Rule::Enum => match parse_enum(inner) {
+            Statement::Enum(mut enumeration) => {
+                enumeration.doc = enumeration.doc.or(doc);
+                Declaration::Enum(enumeration)
+            }
+            s => return s,
+        },
+        Rule::Interface => return parse_interface(inner),
+        _ => return parse_type(inner),
+    };
+
+    Statement::Declare(declaration)
+}
+
+pub fn parse_declare(stmnt: Pair<Rule>) -> Statement {
+    let mut inner = stmnt.into_inner().peekable();
+    let doc = inner
+        .next_if(|p| p.as_rule() == Rule::DocComment)
+        .map(parse_doc_comment);
+
+    parse_ambient(inner.next().unwrap(), doc)
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use pest::iterators::Pair;
+
+use crate::ast::{
+    enumeration::{Enum, EnumMember},
+    operation::{Operation, UnaryOperation},
+    statement::Statement,
+    value::Value,
+};
+
+use super::{comment::parse_doc_comment, expression::parse_expression, literal::parse_string, Rule};
+
+/// Replaces references to earlier members of the enum, like `B = A * 2`, by their value.
+fn resolve(value: &Arc<Value>, members: &[EnumMember]) -> Arc<Value> {
+    match &**value {
+        Value::Identifier(path) if path.len() == 1 => members
+            .iter()
+            .find(|m| m.name == path[0])
+            .map(|m| m.value.clone())
+            .unwrap_or_else(|| value.clone()),
+        Value::Expression { left, op, right } => Arc::new(Value::Expression {
+            left: resolve(left, members),
+            op: *op,
+            right: resolve(right, members),
+        }),
+        Value::Unary {
+            op: UnaryOperation::Negative,
+            value: inner,
+        } => match &**inner {
+            Value::Number(n) => Arc::new(Value::Number(-n)),
+            _ => Arc::new(Value::Unary {
+                op: UnaryOperation::Negative,
+                value: resolve(inner, members),
+            }),
+        },
+        Value::Unary { op, value } => Arc::new(Value::Unary {
+            op: *op,
+            value: resolve(value, members),
+        }),
+        _ => value.clone(),
+    }
+}
+
+pub fn parse_enum(stmnt: Pair<Rule>) -> Statement {
+    let mut doc = None;
+    let mut is_const = false;
+    let mut name = String::new();
+    let mut members: Vec<EnumMember> = Vec::new();
+
+    let parts = stmnt.into_inner().flat_map(|part| match part.as_rule() {
+        Rule::EnumBody => part.into_inner().collect(),
+        _ => vec![part],
+    });
+
+    for part in parts {
+        match part.as_rule() {
+            Rule::DocComment if name.is_empty() => doc = Some(parse_doc_comment(part)),
+            Rule::EnumConst => is_const = true,
+            Rule::Name => name = part.as_str().into(),
+            Rule::EnumMember => {
+                let mut doc = None;
+                let mut member = String::new();
+                let mut value = None;
+
+                for p in part.into_inner() {
+                    match p.as_rule() {
+                        Rule::DocComment => doc = Some(parse_doc_comment(p)),
+                        Rule::Name => member = p.as_str().into(),
+                        Rule::String => member = parse_string(p),
+                        Rule::Expression => value = Some(resolve(&parse_expression(p), &members)),
+                        _ => {}
+                    }
+                }
+
+                // members without initializer count up from the one before
+                let value = value.unwrap_or_else(|| match members.last() {
+                    None => Arc::new(Value::Number(0.0)),
+                    Some(last) => match &*last.value {
+                        Value::Number(n) => Arc::new(Value::Number(n + 1.0)),
+                        _ => Arc::new(Value::Expression {
+                            left: last.value.clone(),
+                            op: Operation::Add,
+                            right: Arc::new(Value::Number(1.0)),
+                        }),
+                    },
+                });
+
+                members.push(EnumMember {
+                    doc,
+                    name: member,
+                    value,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Statement::Enum(Enum {
+        doc,
+        name,
+        is_const,
+        members,
+    })
+}
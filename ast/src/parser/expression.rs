@@ -1,82 +1,214 @@
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
-use pest::iterators::Pair;
+use pest::{
+    iterators::{Pair, Pairs},
+    pratt_parser::{Assoc, Op, PrattParser},
+};
 
-use super::{parse_param_kind, Rule};
-use crate::ast::{operation::AssignOperation, statement::Statement, tstype::TsType, value::Value};
+use super::{
+    function::parse_function,
+    literal::{parse_bigint, parse_number, parse_string, unescape},
+    object::parse_object,
+    pattern::parse_pattern,
+    tstype::parse_ts_type,
+    Rule,
+};
+use crate::ast::{
+    operation::AssignOperation, statement::Statement, value::Value, variable::Declarator,
+};
 
-fn parse_assign_definition(stmnt: Pair<Rule>) -> (String, Vec<TsType>) {
-    let mut inner = stmnt.into_inner();
-    let name = inner.next().unwrap().as_str().to_string();
-    let kinds = if let Some(inn) = inner.next() {
-        parse_param_kind(inn)
-    } else {
-        Vec::new()
-    };
+fn parse_declarator(declarator: Pair<Rule>) -> Declarator {
+    let mut inner = declarator.into_inner();
+    let pattern = parse_pattern(inner.next().unwrap());
+    let mut kind = None;
+    let mut value = None;
 
-    (name, kinds)
+    for part in inner {
+        match part.as_rule() {
+            Rule::TsType => kind = Some(parse_ts_type(part)),
+            Rule::Expression => value = Some(parse_expression(part)),
+            _ => {}
+        }
+    }
+
+    Declarator {
+        pattern,
+        kind,
+        value,
+    }
 }
 
-pub fn parse_const(stmnt: Pair<Rule>) -> Statement {
-    let mut inner = stmnt.into_inner();
-    let (name, _kinds) = parse_assign_definition(inner.next().unwrap());
-    let expr = inner.next().unwrap();
+/// Parses a `const`, `let` or `var` declaration with all of its declarators.
+pub fn parse_variable(stmnt: Pair<Rule>) -> Statement {
+    let rule = stmnt.as_rule();
+    // skips the keyword
+    let declarators = stmnt.into_inner().skip(1).map(parse_declarator).collect();
 
-    Statement::Const {
-        name: name.as_str().to_string(),
-        value: parse_expression(expr),
+    match rule {
+        Rule::Const => Statement::Const(declarators),
+        Rule::Let => Statement::Let(declarators),
+        _ => Statement::Var(declarators),
     }
 }
 
-pub fn parse_let(stmnt: Pair<Rule>) -> Statement {
+pub fn parse_assign_target(target: Pair<Rule>) -> Arc<Value> {
+    let mut inner = target.into_inner();
+    let term = parse_primary(inner.next().unwrap());
+
+    inner.fold(term, parse_postfix)
+}
+
+pub fn parse_destructure(stmnt: Pair<Rule>) -> Value {
     let mut inner = stmnt.into_inner();
-    let (name, _kinds) = parse_assign_definition(inner.next().unwrap());
-    let expr = inner.next().unwrap();
+    let pattern = parse_pattern(inner.next().unwrap());
 
-    Statement::Let {
-        name: name.as_str().to_string(),
-        value: parse_expression(expr),
+    Value::Destructure {
+        pattern,
+        value: parse_expression(inner.next().unwrap()),
     }
 }
 
 pub fn parse_assign(stmnt: Pair<Rule>) -> Value {
     let mut inner = stmnt.into_inner();
-    let name = inner.next().unwrap();
+    let target = parse_assign_target(inner.next().unwrap());
     let op = inner.next().unwrap().as_str().into();
     let expr = inner.next().unwrap();
 
     Value::Assign {
-        identifier: name.as_str().to_string(),
+        target,
         op,
         value: parse_expression(expr),
     }
 }
 
-pub fn parse_call(stmnt: Pair<Rule>) -> Value {
-    let mut inner = stmnt.into_inner();
-    let identifier: Vec<String> = inner
-        .next()
-        .unwrap()
-        .as_str()
-        .split(".")
-        .map(|n| n.to_string())
-        .collect();
+/// Parses an argument or an array element, which might be spread.
+fn parse_element(element: Pair<Rule>) -> Arc<Value> {
+    match element.as_rule() {
+        Rule::Spread => Arc::new(Value::Spread(parse_expression(
+            element.into_inner().next().unwrap(),
+        ))),
+        _ => parse_expression(element),
+    }
+}
 
-    let args = inner
-        .next()
-        .unwrap()
-        .into_inner()
-        .map(|n| parse_expression(n))
-        .collect();
+fn parse_call_list(list: Option<Pair<Rule>>) -> Vec<Arc<Value>> {
+    match list {
+        Some(list) => list.into_inner().map(parse_element).collect(),
+        None => Vec::new(),
+    }
+}
+
+fn parse_new(new: Pair<Rule>) -> Value {
+    let mut inner = new.into_inner();
+    let mut callee = parse_primary(inner.next().unwrap());
+    let mut args = Vec::new();
+
+    for part in inner {
+        match part.as_rule() {
+            Rule::Arguments => {
+                args = parse_call_list(part.into_inner().find(|p| p.as_rule() == Rule::CallList))
+            }
+            _ => callee = parse_postfix(callee, part),
+        }
+    }
+
+    Value::New { callee, args }
+}
 
-    Value::Call { identifier, args }
+fn parse_postfix(object: Arc<Value>, op: Pair<Rule>) -> Arc<Value> {
+    let optional = matches!(
+        op.as_rule(),
+        Rule::OptionalMember | Rule::OptionalIndex | Rule::OptionalCall
+    );
+    let rule = op.as_rule();
+    let mut inner = op.into_inner();
+
+    let value = match rule {
+        Rule::NonNull => Value::NonNull(object),
+        Rule::As => Value::As {
+            value: close_chain(object),
+            kind: parse_ts_type(inner.nth(1).unwrap()),
+        },
+        Rule::Satisfies => Value::Satisfies {
+            value: close_chain(object),
+            kind: parse_ts_type(inner.nth(1).unwrap()),
+        },
+        Rule::Member | Rule::OptionalMember => Value::Member {
+            object,
+            property: inner.next().unwrap().as_str().to_string(),
+            optional,
+        },
+        Rule::Template => {
+            let (quasis, exprs) = parse_template(inner);
+            Value::TaggedTemplate {
+                tag: object,
+                quasis,
+                exprs,
+            }
+        }
+        Rule::Index | Rule::OptionalIndex => Value::Index {
+            object,
+            index: parse_expression(inner.next().unwrap()),
+            optional,
+        },
+        _ => Value::Call {
+            callee: object,
+            args: parse_call_list(inner.find(|p| p.as_rule() == Rule::CallList)),
+            optional,
+        },
+    };
+
+    Arc::new(value)
+}
+
+/// Checks if `value` ends a member/call chain which contains an optional link.
+fn is_optional_chain(value: &Value) -> bool {
+    match value {
+        Value::Member {
+            object, optional, ..
+        }
+        | Value::Index {
+            object, optional, ..
+        } => *optional || is_optional_chain(object),
+        Value::Call {
+            callee, optional, ..
+        } => *optional || is_optional_chain(callee),
+        Value::NonNull(value) => is_optional_chain(value),
+        _ => false,
+    }
+}
+
+/// Wraps a finished optional chain, so the JIT knows where the short circuit ends.
+fn close_chain(value: Arc<Value>) -> Arc<Value> {
+    if is_optional_chain(&value) {
+        Arc::new(Value::Chain(value))
+    } else {
+        value
+    }
+}
+
+fn parse_template(parts: Pairs<Rule>) -> (Vec<String>, Vec<Arc<Value>>) {
+    let mut quasis = Vec::new();
+    let mut exprs = Vec::new();
+
+    for part in parts {
+        match part.as_rule() {
+            Rule::TemplateChars => quasis.push(unescape(part.as_str())),
+            Rule::TemplateSubstitution => {
+                exprs.push(parse_expression(part.into_inner().next().unwrap()))
+            }
+            _ => {}
+        }
+    }
+
+    (quasis, exprs)
 }
 
 pub fn parse_term(term: Pair<Rule>) -> Value {
     match term.as_rule() {
         Rule::PostTerm => {
             let mut inner = term.into_inner();
-            let name = inner.next().unwrap();
+            let target = parse_assign_target(inner.next().unwrap());
             let op = inner.next().unwrap();
 
             let op = match op.as_rule() {
@@ -86,7 +218,7 @@ pub fn parse_term(term: Pair<Rule>) -> Value {
             };
 
             Value::Assign {
-                identifier: name.as_str().to_string(),
+                target,
                 op,
                 value: Arc::new(Value::Number(1.0)),
             }
@@ -94,7 +226,7 @@ pub fn parse_term(term: Pair<Rule>) -> Value {
         Rule::PrefixTerm => {
             let mut inner = term.into_inner();
             let op = inner.next().unwrap();
-            let name = inner.next().unwrap();
+            let target = parse_assign_target(inner.next().unwrap());
 
             let op = match op.as_rule() {
                 Rule::Inc => AssignOperation::Add,
@@ -103,40 +235,60 @@ pub fn parse_term(term: Pair<Rule>) -> Value {
             };
 
             Value::Assign {
-                identifier: name.as_str().to_string(),
+                target,
                 op,
                 value: Arc::new(Value::Number(1.0)),
             }
         }
-        Rule::Call => parse_call(term),
         Rule::Assign => parse_assign(term),
-        Rule::CaseTerm | Rule::Term => {
+        Rule::Destructure => parse_destructure(term),
+        Rule::Closur => Value::Function(parse_function(term)),
+        Rule::Yield => {
+            let mut delegate = false;
+            let mut value = None;
+
+            for part in term.into_inner() {
+                match part.as_rule() {
+                    Rule::YieldDelegate => delegate = true,
+                    Rule::Expression => value = Some(parse_expression(part)),
+                    _ => {}
+                }
+            }
+
+            Value::Yield { value, delegate }
+        }
+        Rule::Term => {
             let inner = term.into_inner().next().unwrap();
 
             match inner.as_rule() {
-                Rule::Number => {
-                    if let Ok(flt) = inner.as_str().parse::<f64>() {
-                        Value::Number(flt)
-                    } else {
-                        Value::Undefined
-                    }
-                }
+                Rule::Number => Value::Number(parse_number(inner)),
+                Rule::BigInt => Value::BigInt(parse_bigint(inner)),
                 Rule::Boolean => Value::Boolean(inner.as_str() == "true"),
+                Rule::Null => Value::Null,
+                Rule::This => Value::This,
+                Rule::Super => Value::Super,
+                Rule::New => parse_new(inner),
+                Rule::FunctionExpression => Value::Function(parse_function(inner)),
+                Rule::DynamicImport => {
+                    Value::DynamicImport(parse_expression(inner.into_inner().next().unwrap()))
+                }
                 Rule::Identifier => {
                     let names: Vec<String> =
                         inner.as_str().split(".").map(|n| n.to_string()).collect();
 
                     Value::Identifier(names)
                 }
-                Rule::String => {
-                    let data = inner.as_str();
-                    Value::String(data[1..data.len() - 1].into())
+                Rule::String => Value::String(parse_string(inner)),
+                Rule::Template => {
+                    let (quasis, exprs) = parse_template(inner.into_inner());
+                    Value::Template { quasis, exprs }
                 }
+                Rule::Object => parse_object(inner),
                 Rule::Array => {
                     let mut array = Vec::new();
 
                     for stmnt in inner.into_inner() {
-                        array.push(parse_expression(stmnt));
+                        array.push(parse_element(stmnt));
                     }
 
                     Value::Array(array)
@@ -148,19 +300,112 @@ pub fn parse_term(term: Pair<Rule>) -> Value {
     }
 }
 
+fn pratt() -> &'static PrattParser<Rule> {
+    static PRATT: OnceLock<PrattParser<Rule>> = OnceLock::new();
+
+    // lowest precedence first
+    PRATT.get_or_init(|| {
+        PrattParser::new()
+            .op(Op::infix(Rule::Conditional, Assoc::Right))
+            .op(Op::infix(Rule::Nullish, Assoc::Left))
+            .op(Op::infix(Rule::Or, Assoc::Left))
+            .op(Op::infix(Rule::And, Assoc::Left))
+            .op(Op::infix(Rule::BitOr, Assoc::Left))
+            .op(Op::infix(Rule::BitXor, Assoc::Left))
+            .op(Op::infix(Rule::BitAnd, Assoc::Left))
+            .op(Op::infix(Rule::Eq, Assoc::Left)
+                | Op::infix(Rule::Ne, Assoc::Left)
+                | Op::infix(Rule::StrictEq, Assoc::Left)
+                | Op::infix(Rule::StrictNe, Assoc::Left))
+            .op(Op::infix(Rule::Lt, Assoc::Left)
+                | Op::infix(Rule::Gt, Assoc::Left)
+                | Op::infix(Rule::Lte, Assoc::Left)
+                | Op::infix(Rule::Gte, Assoc::Left)
+                | Op::infix(Rule::InstanceOf, Assoc::Left)
+                | Op::infix(Rule::In, Assoc::Left)
+                | Op::postfix(Rule::As)
+                | Op::postfix(Rule::Satisfies))
+            .op(Op::infix(Rule::Shl, Assoc::Left)
+                | Op::infix(Rule::Shr, Assoc::Left)
+                | Op::infix(Rule::UShr, Assoc::Left))
+            .op(Op::infix(Rule::Add, Assoc::Left) | Op::infix(Rule::Sub, Assoc::Left))
+            .op(Op::infix(Rule::Mul, Assoc::Left)
+                | Op::infix(Rule::Div, Assoc::Left)
+                | Op::infix(Rule::Mod, Assoc::Left))
+            .op(Op::prefix(Rule::Not)
+                | Op::prefix(Rule::BitNot)
+                | Op::prefix(Rule::Negative)
+                | Op::prefix(Rule::Positive)
+                | Op::prefix(Rule::TypeOf)
+                | Op::prefix(Rule::Void)
+                | Op::prefix(Rule::Delete)
+                | Op::prefix(Rule::Await)
+                | Op::prefix(Rule::TypeAssertion))
+            .op(Op::infix(Rule::Pow, Assoc::Right))
+            .op(Op::postfix(Rule::Member)
+                | Op::postfix(Rule::Template)
+                | Op::postfix(Rule::Index)
+                | Op::postfix(Rule::Arguments)
+                | Op::postfix(Rule::OptionalMember)
+                | Op::postfix(Rule::OptionalIndex)
+                | Op::postfix(Rule::OptionalCall)
+                | Op::postfix(Rule::NonNull))
+    })
+}
+
+fn parse_primary(term: Pair<Rule>) -> Arc<Value> {
+    if term.as_rule() == Rule::Term {
+        let inner = term.clone().into_inner().next().unwrap();
+
+        if inner.as_rule() == Rule::Expression {
+            return parse_expression(inner);
+        }
+    }
+
+    Arc::new(parse_term(term))
+}
+
 pub fn parse_expression(expr: Pair<Rule>) -> Arc<Value> {
-    let mut inner = expr.into_inner();
+    let value = pratt()
+        .map_primary(parse_primary)
+        .map_infix(|left, op, right| {
+            let left = close_chain(left);
+            let right = close_chain(right);
 
-    let term = inner.next().unwrap();
+            let value = match op.as_rule() {
+                Rule::Conditional => Value::Conditional {
+                    cond: left,
+                    then: parse_expression(op.into_inner().next().unwrap()),
+                    els: right,
+                },
+                Rule::Nullish => Value::Nullish { left, right },
+                _ => Value::Expression {
+                    left,
+                    op: op.as_str().into(),
+                    right,
+                },
+            };
 
-    if let Some(op) = inner.next() {
-        let term1 = inner.next().unwrap();
-        Arc::new(Value::Expression {
-            left: Arc::new(parse_term(term)),
-            op: op.as_str().into(),
-            right: Arc::new(parse_term(term1)),
+            Arc::new(value)
         })
-    } else {
-        Arc::new(parse_term(term))
-    }
+        .map_prefix(|op, value| {
+            let value = close_chain(value);
+
+            match op.as_rule() {
+                Rule::Await => Arc::new(Value::Await(value)),
+                Rule::TypeAssertion => Arc::new(Value::As {
+                    value,
+                    kind: parse_ts_type(op.into_inner().next().unwrap()),
+                }),
+                _ => Arc::new(Value::Unary {
+                    op: op.as_str().into(),
+                    value,
+                }),
+            }
+        })
+        .map_postfix(parse_postfix)
+        // documentation in front of an operand is only a comment
+        .parse(expr.into_inner().filter(|p| p.as_rule() != Rule::DocComment));
+
+    close_chain(value)
 }
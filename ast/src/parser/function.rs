@@ -1,80 +1,84 @@
-use std::collections::HashMap;
-
 use pest::iterators::Pair;
 
 use crate::ast::{
     function::{Function, Param},
-    tstype::TsType,
+    statement::Statement,
 };
 
-use super::{parse_statement, Rule, expression::parse_term};
+use super::{
+    comment::parse_doc_comment,
+    expression::parse_expression,
+    parse_statement,
+    pattern::parse_pattern,
+    tstype::{parse_return_type, parse_template_definition, parse_ts_type},
+    Rule,
+};
 
-pub fn parse_param_kind(kind: Pair<Rule>) -> Vec<TsType> {
-    let mut kinds = Vec::new();
+pub fn parse_param(param: Pair<Rule>) -> Param {
+    let mut inner = param.into_inner().peekable();
+    let mut doc = None;
 
-    for k in kind.into_inner() {
-        kinds.push(k.as_str().into());
+    if inner.peek().unwrap().as_rule() == Rule::DocComment {
+        doc = Some(parse_doc_comment(inner.next().unwrap()));
     }
 
-    kinds
-}
-
-pub fn parse_param(param: Pair<Rule>) -> Param {
-    let mut inner = param.into_inner();
-    let name = inner.next().unwrap();
-    let mut kinds = Vec::new();
+    // modifiers only matter for constructor parameters, see `parse_class`
+    inner.next_if(|p| p.as_rule() == Rule::Modifiers);
+    let is_rest = inner.next_if(|p| p.as_rule() == Rule::ParamRest).is_some();
+    let pattern = parse_pattern(inner.next().unwrap());
+    let is_optional = inner.next_if(|p| p.as_rule() == Rule::Optional).is_some();
+    let mut kind = None;
     let mut default = None;
 
     while let Some(t) = inner.next() {
         match t.as_rule() {
-            Rule::TypeIdentifiers => {
-                kinds = parse_param_kind(t);
+            Rule::TsType => {
+                kind = Some(parse_ts_type(t));
             }
-            Rule::Term => {
-                default = Some(parse_term(t));
+            Rule::Expression => {
+                default = Some(parse_expression(t));
             }
             _ => {}
         }
     }
 
     Param {
-        name: name.as_str().to_string(),
-        kinds,
+        doc,
+        pattern,
+        is_rest,
+        is_optional,
+        kind,
         default,
     }
 }
 
-pub fn parse_template_definition(tmp: Pair<Rule>) -> (String, Vec<TsType>) {
-    let mut inner = tmp.into_inner();
-
-    let name = inner.next().unwrap().as_str();
-
-    let kinds = if let Some(typedef) = inner.next() {
-        parse_param_kind(typedef)
-    } else {
-        Vec::new()
-    };
-
-    (name.into(), kinds)
-}
-
 pub fn parse_function(func: Pair<Rule>) -> Function {
+    let mut doc = None;
+    let mut is_async = false;
+    let mut is_generator = false;
+    let mut has_body = false;
     let mut name = None;
     let mut params = Vec::new();
-    let mut returns = Vec::new();
+    let mut returns = None;
     let mut block_statements = Vec::new();
-    let mut template_args = HashMap::new();
+    let mut template_args = Vec::new();
 
     for inner in func.into_inner() {
         match inner.as_rule() {
+            Rule::DocComment => {
+                doc = Some(parse_doc_comment(inner));
+            }
+            Rule::Async => {
+                is_async = true;
+            }
+            Rule::Generator => {
+                is_generator = true;
+            }
             Rule::Name => {
                 name = Some(inner.as_str().into());
             }
             Rule::TemplateDefinition => {
-                for p in inner.into_inner() {
-                    let (name, kinds) = parse_template_definition(p);
-                    template_args.insert(name, kinds);
-                }
+                template_args = parse_template_definition(inner);
             }
             Rule::FunctionDefinition => {
                 for p in inner.into_inner() {
@@ -85,15 +89,23 @@ pub fn parse_function(func: Pair<Rule>) -> Function {
                             }
                         }
                         Rule::ReturnType => {
-                            for r in p.into_inner() {
-                                returns.push(r.as_str().into());
-                            }
+                            returns = Some(parse_return_type(p.into_inner().next().unwrap()));
                         }
                         _ => {}
                     }
                 }
             }
+            // arrow functions with a single parameter without parentheses
+            Rule::Param => {
+                params.push(parse_param(inner));
+            }
+            // arrow functions with an expression body
+            Rule::Expression => {
+                has_body = true;
+                block_statements.push(Statement::Return(parse_expression(inner)));
+            }
             Rule::Block => {
+                has_body = true;
                 for stmnt in inner.into_inner() {
                     if let Some(s) = parse_statement(stmnt) {
                         block_statements.push(s);
@@ -105,8 +117,11 @@ pub fn parse_function(func: Pair<Rule>) -> Function {
     }
 
     let f = Function {
+        doc,
         name,
-        is_async: false,
+        is_async,
+        is_generator,
+        is_declaration: !has_body,
         template_args,
         params,
         returns,
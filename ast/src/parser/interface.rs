@@ -3,21 +3,33 @@ use pest::iterators::Pair;
 
 use crate::ast::{statement::Statement, interface::Interface};
 
-use super::{Rule, parse_function, function::parse_param};
+use super::{
+    comment::parse_doc_comment, function::parse_param, parse_function,
+    tstype::{parse_reference, parse_template_definition},
+    Rule,
+};
 
 pub fn parse_interface(stmnt: Pair<Rule>) -> Statement {
-    let mut inner = stmnt.into_inner();
+    let mut inner = stmnt.into_inner().peekable();
+    let mut doc = None;
+
+    if inner.peek().unwrap().as_rule() == Rule::DocComment {
+        doc = Some(parse_doc_comment(inner.next().unwrap()));
+    }
 
     let name = inner.next().unwrap().as_str();
-    let mut extends = None;
+    let mut template_args = Vec::new();
+    let mut extends = Vec::new();
     let mut attributes = Vec::new();
     let mut methods = Vec::new();
 
     while let Some(block) = inner.next() {
         match block.as_rule() {
+            Rule::TemplateDefinition => {
+                template_args = parse_template_definition(block);
+            }
             Rule::InterfaceExtends => {
-                let inner = block.into_inner().next().unwrap();
-                extends = Some(inner.as_str().into());
+                extends = block.into_inner().map(parse_reference).collect();
             }
             Rule::InterfaceBody => {
                 for part in block.into_inner() {
@@ -37,7 +49,9 @@ pub fn parse_interface(stmnt: Pair<Rule>) -> Statement {
     }
 
     let interface = Interface {
+        doc,
         name: name.to_string(),
+        template_args,
         extends,
         attributes,
         methods,
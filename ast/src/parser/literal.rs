@@ -0,0 +1,149 @@
+use pest::iterators::Pair;
+
+use super::Rule;
+
+fn read_hex(chars: &[char], start: usize, len: usize) -> Option<u32> {
+    let digits: String = chars.get(start..start + len)?.iter().collect();
+    u32::from_str_radix(&digits, 16).ok()
+}
+
+/// Reads the code unit of a `\uXXXX` or `\u{X...}` escape, `pos` points behind the `u`.
+fn read_unicode(chars: &[char], pos: &mut usize) -> u32 {
+    if chars.get(*pos) == Some(&'{') {
+        let end = chars[*pos..].iter().position(|c| *c == '}').unwrap() + *pos;
+        let code = read_hex(chars, *pos + 1, end - *pos - 1).unwrap_or(0xFFFD);
+        *pos = end + 1;
+        code
+    } else {
+        let code = read_hex(chars, *pos, 4).unwrap();
+        *pos += 4;
+        code
+    }
+}
+
+/// Decodes the escape sequences of a string or template literal.
+pub fn unescape(raw: &str) -> String {
+    let chars: Vec<char> = raw.chars().collect();
+    let mut out = String::with_capacity(raw.len());
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let c = chars[pos];
+        pos += 1;
+
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        let c = chars[pos];
+        pos += 1;
+
+        match c {
+            'n' => out.push('\n'),
+            't' => out.push('\t'),
+            'r' => out.push('\r'),
+            'b' => out.push('\u{8}'),
+            'f' => out.push('\u{c}'),
+            'v' => out.push('\u{b}'),
+            '0' if !chars.get(pos).is_some_and(char::is_ascii_digit) => out.push('\0'),
+            'x' => {
+                let code = read_hex(&chars, pos, 2).unwrap();
+                pos += 2;
+                out.push(char::from_u32(code).unwrap());
+            }
+            'u' => {
+                let mut code = read_unicode(&chars, &mut pos);
+
+                // combine surrogate pairs like `\uD83D\uDE00`, lone surrogates become U+FFFD
+                if (0xD800..0xDC00).contains(&code)
+                    && chars.get(pos) == Some(&'\\')
+                    && chars.get(pos + 1) == Some(&'u')
+                {
+                    let mut next = pos + 2;
+                    let low = read_unicode(&chars, &mut next);
+
+                    if (0xDC00..0xE000).contains(&low) {
+                        code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                        pos = next;
+                    }
+                }
+
+                out.push(char::from_u32(code).unwrap_or('\u{FFFD}'));
+            }
+            // line continuations
+            '\r' => {
+                if chars.get(pos) == Some(&'\n') {
+                    pos += 1;
+                }
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+pub fn parse_string(string: Pair<Rule>) -> String {
+    let data = string.as_str();
+    unescape(&data[1..data.len() - 1])
+}
+
+/// Splits a numeric literal into its radix and digits, dropping separators.
+fn split_radix(literal: &str) -> (u32, String) {
+    let digits = literal.replace('_', "");
+
+    match digits.get(..2) {
+        Some("0x" | "0X") => (16, digits[2..].into()),
+        Some("0o" | "0O") => (8, digits[2..].into()),
+        Some("0b" | "0B") => (2, digits[2..].into()),
+        _ => (10, digits),
+    }
+}
+
+pub fn parse_number(number: Pair<Rule>) -> f64 {
+    let (radix, digits) = split_radix(number.as_str());
+
+    if radix == 10 {
+        // the grammar only lets valid decimal literals through
+        digits.parse().unwrap()
+    } else {
+        digits
+            .chars()
+            .fold(0.0, |acc, c| acc * radix as f64 + c.to_digit(radix).unwrap() as f64)
+    }
+}
+
+/// Returns the decimal digits of a BigInt literal, without the `n` suffix.
+pub fn parse_bigint(bigint: Pair<Rule>) -> String {
+    let literal = bigint.as_str();
+    let (radix, digits) = split_radix(&literal[..literal.len() - 1]);
+
+    // little endian decimal digits, converted one input digit at a time
+    let mut decimal: Vec<u32> = vec![0];
+    for c in digits.chars() {
+        let mut carry = c.to_digit(radix).unwrap();
+
+        for d in decimal.iter_mut() {
+            let v = *d * radix + carry;
+            *d = v % 10;
+            carry = v / 10;
+        }
+
+        while carry > 0 {
+            decimal.push(carry % 10);
+            carry /= 10;
+        }
+    }
+
+    while decimal.len() > 1 && decimal.last() == Some(&0) {
+        decimal.pop();
+    }
+
+    decimal
+        .iter()
+        .rev()
+        .map(|d| char::from_digit(*d, 10).unwrap())
+        .collect()
+}
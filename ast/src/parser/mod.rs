@@ -1,43 +1,59 @@
-use std::{error::Error, path::Path, sync::Arc};
+use std::{error::Error, path::Path};
 
 use crate::ast::{
-    function::Param,
-    module::{Import, ImportAlias, Module},
+    module::{Import, ImportAlias, Module, Reexport},
+    pattern::Pattern,
+    value::Value,
     statement::Statement,
     trycatch::TryCatch,
-    typedefinition::{TypeBlock, TypeDefinition},
+    tstype::TsType,
+    typedefinition::TypeDefinition,
+    variable::Declarator,
 };
 use pest::{iterators::Pair, Parser};
 use pest_derive::Parser;
 
 use self::{
     class::parse_class,
-    expression::{parse_const, parse_expression, parse_let, parse_term},
+    declaration::{parse_ambient, parse_declare},
+    enumeration::parse_enum,
+    comment::parse_doc_comment,
+    expression::{parse_expression, parse_variable},
     ifs::parse_if,
-    interface::parse_interface,
-    repeat::{parse_for, parse_for_in, parse_for_of},
-    switch::parse_switch, function::{parse_function, parse_param_kind},
+    literal::parse_string,
+    namespace::{attach_doc, declared_names, parse_member},
+    repeat::{parse_do_while, parse_for, parse_for_in, parse_for_of, parse_while},
+    switch::parse_switch, function::parse_function,
+    tstype::{parse_template_definition, parse_ts_type},
 };
 
 mod class;
+mod comment;
+mod declaration;
+mod enumeration;
 mod expression;
 mod function;
 mod ifs;
 mod interface;
+mod literal;
+mod namespace;
+mod object;
+mod pattern;
 mod repeat;
 mod switch;
+mod tstype;
 
 #[derive(Parser)]
 #[grammar = "parser/typescript.pest"] // relative to src
 pub struct TypeScriptParser;
 
 fn parse_statement(stmnt: Pair<Rule>) -> Option<Statement> {
-    let stmnt = stmnt.into_inner().next().unwrap();
+    // doc comments which do not belong to a declaration have no inner rules
+    let stmnt = stmnt.into_inner().next()?;
 
     match stmnt.as_rule() {
-        Rule::Const => Some(parse_const(stmnt)),
-        Rule::Let => Some(parse_let(stmnt)),
-        Rule::Assign => Some(Statement::Expression(Arc::new(parse_term(stmnt)))),
+        Rule::Const | Rule::Let | Rule::Var => Some(parse_variable(stmnt)),
+        Rule::Expression => Some(Statement::Expression(parse_expression(stmnt))),
         Rule::If => {
             let _if = parse_if(stmnt);
             Some(Statement::If(_if))
@@ -46,6 +62,8 @@ fn parse_statement(stmnt: Pair<Rule>) -> Option<Statement> {
             let switch = parse_switch(stmnt);
             Some(Statement::Switch(switch))
         }
+        Rule::While => Some(Statement::Loop(parse_while(stmnt))),
+        Rule::DoWhile => Some(Statement::Loop(parse_do_while(stmnt))),
         Rule::For => {
             let repeat = parse_for(stmnt);
             Some(Statement::Loop(repeat))
@@ -58,10 +76,27 @@ fn parse_statement(stmnt: Pair<Rule>) -> Option<Statement> {
             let repeat = parse_for_in(stmnt);
             Some(Statement::Loop(repeat))
         }
+        Rule::Enum => Some(parse_enum(stmnt)),
         Rule::Function => {
             let func = parse_function(stmnt);
             Some(Statement::Function(func))
         }
+        Rule::Break => Some(Statement::Break(
+            stmnt.into_inner().next().map(|label| label.as_str().into()),
+        )),
+        Rule::Continue => Some(Statement::Continue(
+            stmnt.into_inner().next().map(|label| label.as_str().into()),
+        )),
+        Rule::Labeled => {
+            let mut inner = stmnt.into_inner();
+            let label = inner.next().unwrap().as_str().into();
+
+            Some(Statement::Labeled {
+                label,
+                statement: Box::new(parse_statement(inner.next().unwrap())?),
+            })
+        }
+        Rule::Block => Some(Statement::Block(parse_statements(stmnt))),
         Rule::Return => Some(Statement::Return(parse_expression(
             stmnt.into_inner().next().unwrap(),
         ))),
@@ -100,48 +135,203 @@ fn parse_trycatch(stmnt: Pair<Rule>) -> TryCatch {
 }
 
 fn parse_type(stmnt: Pair<Rule>) -> Statement {
-    let mut inner = stmnt.into_inner();
-
-    let name = inner.next().unwrap().as_str();
-    let mut blocks = Vec::new();
-    let mut aggregates = Vec::new();
-
-    let definitions = inner.next().unwrap().into_inner();
-    for def in definitions {
-        match def.as_rule() {
-            Rule::TypeBlock => {
-                let mut attributes = Vec::new();
-                for tuple in def.into_inner() {
-                    let mut inner = tuple.into_inner();
-
-                    let name = inner.next().unwrap().as_str();
-                    let kind = inner.next().unwrap();
-                    attributes.push(Param {
-                        name: name.to_string(),
-                        kinds: parse_param_kind(kind),
-                        default: None,
-                    });
-                }
+    let mut doc = None;
+    let mut name = String::new();
+    let mut template_args = Vec::new();
+    let mut kind = TsType::Any;
 
-                blocks.push(TypeBlock { attributes })
-            }
-            Rule::Name => {
-                aggregates.push(def.as_str().to_string());
-            }
+    for part in stmnt.into_inner() {
+        match part.as_rule() {
+            Rule::DocComment => doc = Some(parse_doc_comment(part)),
+            Rule::Name => name = part.as_str().into(),
+            Rule::TemplateDefinition => template_args = parse_template_definition(part),
+            Rule::TsType => kind = parse_ts_type(part),
             _ => {}
         }
     }
 
     Statement::Type(TypeDefinition {
-        name: name.to_string(),
-        blocks,
-        aggregates,
+        doc,
+        name,
+        template_args,
+        kind,
     })
 }
 
+/// Reads a script, or the declarations of a `.d.ts` file.
 pub fn file<T: AsRef<Path>>(filename: T) -> Result<Module, Box<dyn Error>> {
+    let is_declaration = filename
+        .as_ref()
+        .to_str()
+        .is_some_and(|f| f.ends_with(".d.ts"));
     let src = std::fs::read_to_string(filename)?;
-    source(&src)
+
+    if is_declaration {
+        declarations(&src)
+    } else {
+        source(&src)
+    }
+}
+
+/// Parses `{ a, type B, c as d }`, types which are imported with `type` are returned apart.
+fn parse_import_names(list: Pair<Rule>) -> (Vec<ImportAlias>, Vec<ImportAlias>) {
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+
+    for n in list.into_inner() {
+        let mut inner = n.into_inner().peekable();
+        let is_type = inner.next_if(|p| p.as_rule() == Rule::ImportType).is_some();
+        let name = inner.next().unwrap();
+        let name_str = name.as_str();
+
+        let alias = if let Some(alias) = inner.next() {
+            let alias_str = alias.as_str();
+            ImportAlias::Alias {
+                name: name_str.to_string(),
+                alias: alias_str.to_string(),
+            }
+        } else {
+            ImportAlias::None {
+                name: name_str.to_string(),
+            }
+        };
+
+        if is_type {
+            types.push(alias);
+        } else {
+            names.push(alias);
+        }
+    }
+
+    (names, types)
+}
+
+fn parse_import(stmnt: Pair<Rule>, imports: &mut Vec<Import>) {
+    if stmnt.as_rule() == Rule::Import {
+        let path = stmnt.into_inner().next().unwrap();
+        imports.push(Import::Normal {
+            path: parse_string(path),
+        });
+
+        return;
+    }
+
+    let mut is_type = false;
+    let mut default = None;
+    let mut namespace = None;
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+    let mut path = String::new();
+
+    for part in stmnt.into_inner() {
+        match part.as_rule() {
+            Rule::ImportType => is_type = true,
+            Rule::Name => default = Some(part.as_str().to_string()),
+            Rule::ImportNamespace => {
+                namespace = Some(part.into_inner().next().unwrap().as_str().to_string())
+            }
+            Rule::ImportList => {
+                if let Some(list) = part.into_inner().next() {
+                    (names, types) = parse_import_names(list);
+                }
+            }
+            Rule::String => path = parse_string(part),
+            _ => {}
+        }
+    }
+
+    if is_type {
+        // the default and the namespace are types as well
+        let default = default.map(|alias| ImportAlias::Alias {
+            name: "default".into(),
+            alias,
+        });
+        let namespace = namespace.map(|alias| ImportAlias::Alias {
+            name: "*".into(),
+            alias,
+        });
+        types.extend(default.into_iter().chain(namespace).chain(names));
+
+        imports.push(Import::Type { names: types, path });
+        return;
+    }
+
+    if !types.is_empty() {
+        imports.push(Import::Type {
+            names: types,
+            path: path.clone(),
+        });
+    }
+
+    imports.push(Import::From {
+        default,
+        namespace,
+        names,
+        path,
+    });
+}
+
+/// The names of an export list, `{ a, b as c }`, as `(exported, local)` pairs.
+fn parse_export_list(list: Pair<Rule>) -> Vec<ImportAlias> {
+    match list.into_inner().next() {
+        Some(names) => parse_import_names(names).0,
+        None => Vec::new(),
+    }
+}
+
+fn parse_export(stmnt: Pair<Rule>, module: &mut Module) {
+    match stmnt.as_rule() {
+        Rule::ExportFrom => {
+            let mut inner = stmnt.into_inner().skip(1);
+            let what = inner.next().unwrap();
+            let path = parse_string(inner.next().unwrap());
+
+            let reexport = match what.as_rule() {
+                Rule::ExportAll => Reexport::All {
+                    alias: what.into_inner().next().map(|n| n.as_str().into()),
+                    path,
+                },
+                _ => Reexport::Names {
+                    names: parse_export_list(what),
+                    path,
+                },
+            };
+            module.reexports.push(reexport);
+        }
+        Rule::ExportNames => {
+            for name in parse_export_list(stmnt.into_inner().nth(1).unwrap()) {
+                let (local, exported) = match name {
+                    ImportAlias::None { name } => (name.clone(), name),
+                    ImportAlias::Alias { name, alias } => (name, alias),
+                };
+                module.exports.insert(exported, Value::Identifier(vec![local]));
+            }
+        }
+        // `export default`
+        _ => {
+            let mut inner = stmnt.into_inner().peekable();
+            let doc = inner
+                .next_if(|p| p.as_rule() == Rule::DocComment)
+                .map(parse_doc_comment);
+            inner.next();
+            let part = inner.next().unwrap();
+
+            let stmnt = match part.as_rule() {
+                Rule::Function => Statement::Function(parse_function(part)),
+                Rule::Class => parse_class(part),
+                // the value is only evaluated once, it is kept in a variable named `default`
+                _ => Statement::Const(vec![Declarator {
+                    pattern: Pattern::Name("default".into()),
+                    kind: None,
+                    value: Some(parse_expression(part)),
+                }]),
+            };
+
+            let local = declared_names(&stmnt).pop().unwrap_or_default();
+            module.exports.insert("default".into(), Value::Identifier(vec![local]));
+            module.statements.push(attach_doc(stmnt, doc));
+        }
+    }
 }
 
 pub fn source(source: &str) -> Result<Module, Box<dyn Error>> {
@@ -152,59 +342,48 @@ pub fn source(source: &str) -> Result<Module, Box<dyn Error>> {
 
     for stmnt in rules.into_inner() {
         match stmnt.as_rule() {
-            Rule::Import => {
-                let path = stmnt.into_inner().next().unwrap();
-                let name = path.as_str();
-                let clean_name = &name[1..name.len() - 1];
-
-                module.imports.push(Import::Normal {
-                    path: clean_name.to_string(),
-                });
+            Rule::Import | Rule::ImportFrom => {
+                parse_import(stmnt, &mut module.imports);
             }
-            Rule::ImportFrom => {
-                let mut inner = stmnt.into_inner();
-                let namelist = inner.next().unwrap();
-                let mut names = Vec::new();
-
-                for n in namelist.into_inner() {
-                    let mut inner = n.into_inner();
-                    let name = inner.next().unwrap();
-                    let name_str = name.as_str();
-
-                    if let Some(alias) = inner.next() {
-                        let alias_str = alias.as_str();
-                        names.push(ImportAlias::Alias {
-                            name: name_str.to_string(),
-                            alias: alias_str.to_string(),
-                        });
-                    } else {
-                        names.push(ImportAlias::None {
-                            name: name_str.to_string(),
-                        });
+            Rule::ExportFrom | Rule::ExportNames | Rule::ExportDefault => {
+                parse_export(stmnt, &mut module);
+            }
+            _ => {
+                if let Some((s, is_exported)) = parse_member(stmnt) {
+                    // types have no value which could be exported
+                    if is_exported && !matches!(s, Statement::Interface(_) | Statement::Type(_)) {
+                        for name in declared_names(&s) {
+                            module
+                                .exports
+                                .insert(name.clone(), Value::Identifier(vec![name]));
+                        }
                     }
+                    module.statements.push(s);
                 }
+            }
+        }
+    }
 
-                let file = inner.next().unwrap();
-                let filename = file.as_str();
+    Ok(module)
+}
 
-                module.imports.push(Import::From {
-                    names,
-                    path: filename[1..filename.len() - 1].to_string(),
-                })
-            }
-            Rule::Interface => {
-                module.statements.push(parse_interface(stmnt));
-            }
-            Rule::Class => {
-                module.statements.push(parse_class(stmnt));
+/// Parses the source of a `.d.ts` file, everything in it is a declaration.
+pub fn declarations(source: &str) -> Result<Module, Box<dyn Error>> {
+    let mut module = Module::new();
+    let rules = TypeScriptParser::parse(Rule::Declarations, source)?
+        .next()
+        .unwrap();
+
+    for stmnt in rules.into_inner() {
+        match stmnt.as_rule() {
+            Rule::Import | Rule::ImportFrom => {
+                parse_import(stmnt, &mut module.imports);
             }
-            Rule::Type => {
-                module.statements.push(parse_type(stmnt));
+            Rule::Declare => {
+                module.statements.push(parse_declare(stmnt));
             }
-            Rule::Statement => {
-                if let Some(s) = parse_statement(stmnt) {
-                    module.statements.push(s);
-                }
+            Rule::Ambient => {
+                module.statements.push(parse_ambient(stmnt, None));
             }
             _ => {}
         }
@@ -0,0 +1,109 @@
+use pest::iterators::Pair;
+
+use crate::ast::{namespace::Namespace, statement::Statement};
+
+use super::{
+    class::parse_class, comment::parse_doc_comment, declaration::parse_declare,
+    interface::parse_interface, parse_statement, parse_type, Rule,
+};
+
+/// The names a declaration makes available, which is what `export` in front of it exports.
+pub fn declared_names(stmnt: &Statement) -> Vec<String> {
+    match stmnt {
+        Statement::Const(declarators)
+        | Statement::Let(declarators)
+        | Statement::Var(declarators) => declarators
+            .iter()
+            .flat_map(|d| d.pattern.names())
+            .map(String::from)
+            .collect(),
+        Statement::Function(f) => f.name.iter().cloned().collect(),
+        Statement::Class(c) => vec![c.name.clone()],
+        Statement::Enum(e) => vec![e.name.clone()],
+        Statement::Interface(i) => vec![i.name.clone()],
+        Statement::Type(t) => vec![t.name.clone()],
+        Statement::Namespace(n) => vec![n.name.clone()],
+        _ => Vec::new(),
+    }
+}
+
+/// Hands a doc comment in front of `export` over to the declaration.
+pub fn attach_doc(mut stmnt: Statement, doc: Option<String>) -> Statement {
+    match &mut stmnt {
+        Statement::Function(f) => f.doc = f.doc.take().or(doc),
+        Statement::Class(c) => c.doc = c.doc.take().or(doc),
+        Statement::Enum(e) => e.doc = e.doc.take().or(doc),
+        Statement::Interface(i) => i.doc = i.doc.take().or(doc),
+        Statement::Namespace(n) => n.doc = n.doc.take().or(doc),
+        _ => {}
+    }
+
+    stmnt
+}
+
+/// Parses a declaration of a module or a namespace, together with whether it is exported.
+pub fn parse_member(member: Pair<Rule>) -> Option<(Statement, bool)> {
+    let stmnt = match member.as_rule() {
+        Rule::Exported => {
+            let mut inner = member.into_inner().peekable();
+            let doc = inner
+                .next_if(|p| p.as_rule() == Rule::DocComment)
+                .map(parse_doc_comment);
+            // the `export` keyword
+            inner.next();
+            let (stmnt, _) = parse_member(inner.next().unwrap())?;
+
+            return Some((attach_doc(stmnt, doc), true));
+        }
+        Rule::Declare => parse_declare(member),
+        Rule::Namespace => parse_namespace(member),
+        Rule::Interface => parse_interface(member),
+        Rule::Class => parse_class(member),
+        Rule::Type => parse_type(member),
+        Rule::Statement => parse_statement(member)?,
+        _ => return None,
+    };
+
+    Some((stmnt, false))
+}
+
+pub fn parse_namespace(stmnt: Pair<Rule>) -> Statement {
+    let mut inner = stmnt.into_inner().peekable();
+    let doc = inner
+        .next_if(|p| p.as_rule() == Rule::DocComment)
+        .map(parse_doc_comment);
+    let path = inner.next().unwrap().as_str();
+
+    let mut statements = Vec::new();
+    let mut exports = Vec::new();
+
+    for member in inner.next().unwrap().into_inner() {
+        if let Some((stmnt, is_exported)) = parse_member(member) {
+            if is_exported {
+                exports.extend(declared_names(&stmnt));
+            }
+            statements.push(stmnt);
+        }
+    }
+
+    // `namespace A.B {}` is the same as `namespace A { export namespace B {} }`
+    let mut names = path.rsplit('.');
+    let mut namespace = Namespace {
+        doc: None,
+        name: names.next().unwrap().into(),
+        statements,
+        exports,
+    };
+
+    for name in names {
+        namespace = Namespace {
+            doc: None,
+            name: name.into(),
+            exports: vec![namespace.name.clone()],
+            statements: vec![Statement::Namespace(namespace)],
+        };
+    }
+
+    namespace.doc = doc;
+    Statement::Namespace(namespace)
+}
@@ -0,0 +1,59 @@
+use pest::iterators::Pair;
+
+use crate::ast::{
+    object::{Property, PropertyKey},
+    value::Value,
+};
+
+use super::{
+    expression::parse_expression,
+    function::parse_function,
+    literal::{parse_number, parse_string},
+    Rule,
+};
+
+pub fn parse_property_key(key: Pair<Rule>) -> PropertyKey {
+    match key.as_rule() {
+        Rule::ComputedKey => PropertyKey::Computed(parse_expression(key.into_inner().next().unwrap())),
+        Rule::String => PropertyKey::Name(parse_string(key)),
+        Rule::Number => PropertyKey::Name(parse_number(key).to_string()),
+        _ => PropertyKey::Name(key.as_str().to_string()),
+    }
+}
+
+pub fn parse_object(object: Pair<Rule>) -> Value {
+    let mut properties = Vec::new();
+
+    for prop in object.into_inner() {
+        match prop.as_rule() {
+            Rule::ObjectSpread => {
+                let value = parse_expression(prop.into_inner().next().unwrap());
+                properties.push(Property::Spread(value));
+            }
+            Rule::ObjectMethod => {
+                let key = prop
+                    .clone()
+                    .into_inner()
+                    .find(|p| !matches!(p.as_rule(), Rule::Async | Rule::Generator))
+                    .unwrap();
+                let key = parse_property_key(key);
+                properties.push(Property::Method {
+                    key,
+                    function: parse_function(prop),
+                });
+            }
+            Rule::ObjectValue => {
+                let mut inner = prop.into_inner();
+                let key = parse_property_key(inner.next().unwrap());
+                let value = parse_expression(inner.next().unwrap());
+                properties.push(Property::Value { key, value });
+            }
+            Rule::ObjectShorthand => {
+                properties.push(Property::Shorthand(prop.as_str().to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    Value::Object(properties)
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use pest::iterators::Pair;
+
+use crate::ast::{
+    pattern::{Pattern, PatternElement, PatternProperty},
+    value::Value,
+};
+
+use super::{
+    expression::{parse_assign_target, parse_expression},
+    object::parse_property_key,
+    Rule,
+};
+
+/// Splits a pattern part into its target and the optional default value.
+fn parse_pattern_part(part: Pair<Rule>) -> (Pattern, Option<Arc<Value>>) {
+    let mut inner = part.into_inner();
+    let pattern = parse_pattern(inner.next().unwrap());
+    let default = inner.next().map(parse_expression);
+
+    (pattern, default)
+}
+
+fn parse_rest(rest: Pair<Rule>) -> Option<Box<Pattern>> {
+    Some(Box::new(parse_pattern(rest.into_inner().next().unwrap())))
+}
+
+pub fn parse_pattern(pattern: Pair<Rule>) -> Pattern {
+    match pattern.as_rule() {
+        Rule::Binding => parse_pattern(pattern.into_inner().next().unwrap()),
+        Rule::Name => Pattern::Name(pattern.as_str().to_string()),
+        Rule::AssignTarget => {
+            let target = parse_assign_target(pattern);
+
+            match &*target {
+                Value::Identifier(path) if path.len() == 1 => Pattern::Name(path[0].clone()),
+                _ => Pattern::Target(target),
+            }
+        }
+        Rule::ArrayPattern => {
+            let mut elements = Vec::new();
+            let mut rest = None;
+
+            for item in pattern.into_inner() {
+                match item.as_rule() {
+                    Rule::PatternRest => rest = parse_rest(item),
+                    Rule::PatternElement => {
+                        let (pattern, default) = parse_pattern_part(item);
+                        elements.push(Some(PatternElement { pattern, default }));
+                    }
+                    _ => elements.push(None),
+                }
+            }
+
+            Pattern::Array { elements, rest }
+        }
+        _ => {
+            let mut properties = Vec::new();
+            let mut rest = None;
+
+            for item in pattern.into_inner() {
+                match item.as_rule() {
+                    Rule::PatternRest => rest = parse_rest(item),
+                    _ => {
+                        let mut inner = item.into_inner();
+                        let key = inner.next().unwrap();
+                        let mut pattern = Pattern::Name(key.as_str().to_string());
+                        let key = parse_property_key(key);
+                        let mut default = None;
+
+                        for part in inner {
+                            match part.as_rule() {
+                                Rule::Expression => default = Some(parse_expression(part)),
+                                _ => pattern = parse_pattern(part),
+                            }
+                        }
+
+                        properties.push(PatternProperty {
+                            key,
+                            pattern,
+                            default,
+                        });
+                    }
+                }
+            }
+
+            Pattern::Object { properties, rest }
+        }
+    }
+}
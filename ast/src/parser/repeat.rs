@@ -2,12 +2,27 @@ use pest::iterators::Pair;
 
 use crate::ast::repeat::Loop;
 
-use super::{Rule, expression::{parse_expression, parse_let}, parse_statement};
+use super::{Rule, expression::{parse_expression, parse_variable}, parse_statement, parse_statements, pattern::parse_pattern};
 
+pub fn parse_while(stmnt: Pair<Rule>) -> Loop {
+    let mut inner = stmnt.into_inner();
+    let cond = parse_expression(inner.next().unwrap());
+    let block = parse_statements(inner.next().unwrap());
+
+    Loop::While { cond, block }
+}
+
+pub fn parse_do_while(stmnt: Pair<Rule>) -> Loop {
+    let mut inner = stmnt.into_inner();
+    let block = parse_statements(inner.next().unwrap());
+    let cond = parse_expression(inner.next().unwrap());
+
+    Loop::DoWhile { block, cond }
+}
 
 pub fn parse_for(stmnt: Pair<Rule>) -> Loop {
     let mut inner = stmnt.into_inner();
-    let init = vec![parse_let(inner.next().unwrap())];
+    let init = vec![parse_variable(inner.next().unwrap())];
     let cond = parse_expression(inner.next().unwrap());
     let after = parse_expression(inner.next().unwrap());
     let mut block = Vec::new();
@@ -27,8 +42,9 @@ pub fn parse_for(stmnt: Pair<Rule>) -> Loop {
 }
 
 pub fn parse_for_of(stmnt: Pair<Rule>) -> Loop {
-    let mut inner = stmnt.into_inner();
-    let name = inner.next().unwrap().as_str().to_string();
+    // skips the `const`, `let` or `var` keyword
+    let mut inner = stmnt.into_inner().skip(1);
+    let pattern = parse_pattern(inner.next().unwrap());
     let value = parse_expression(inner.next().unwrap());
     let mut block = Vec::new();
 
@@ -38,12 +54,12 @@ pub fn parse_for_of(stmnt: Pair<Rule>) -> Loop {
         }
     }
 
-    Loop::ForOf { name, value, block }
+    Loop::ForOf { pattern, value, block }
 }
 
 pub fn parse_for_in(stmnt: Pair<Rule>) -> Loop {
-    let mut inner = stmnt.into_inner();
-    let name = inner.next().unwrap().as_str().to_string();
+    let mut inner = stmnt.into_inner().skip(1);
+    let pattern = parse_pattern(inner.next().unwrap());
     let value = parse_expression(inner.next().unwrap());
     let mut block = Vec::new();
 
@@ -53,5 +69,5 @@ pub fn parse_for_in(stmnt: Pair<Rule>) -> Loop {
         }
     }
 
-    Loop::ForIn { name, value, block }
+    Loop::ForIn { pattern, value, block }
 }
\ No newline at end of file
@@ -1,10 +1,8 @@
-use std::sync::Arc;
-
 use pest::iterators::Pair;
 
 use crate::ast::switch::{Switch, Case};
 
-use super::{Rule, expression::{parse_expression, parse_term}, parse_statement};
+use super::{Rule, expression::parse_expression, parse_statement};
 
 pub fn parse_switch(stmnt: Pair<Rule>) -> Switch {
     let mut inner = stmnt.into_inner();
@@ -17,18 +15,12 @@ pub fn parse_switch(stmnt: Pair<Rule>) -> Switch {
         match stmnt.as_rule() {
             Rule::Case => {
                 let mut inner = stmnt.into_inner();
-                let expr = Arc::new(parse_term(inner.next().unwrap()));
+                let expr = parse_expression(inner.next().unwrap());
                 let mut block = Vec::new();
 
                 while let Some(stmnt) = inner.next() {
-                    match stmnt.as_rule() {
-                        Rule::Statement => {
-                            if let Some(stmnt) = parse_statement(stmnt) {
-                                block.push(stmnt);
-                            }
-                        }
-                        Rule::Break => break,
-                        _ => {}
+                    if let Some(stmnt) = parse_statement(stmnt) {
+                        block.push(stmnt);
                     }
                 }
 
@@ -44,7 +36,6 @@ pub fn parse_switch(stmnt: Pair<Rule>) -> Switch {
                                 block.push(stmnt);
                             }
                         }
-                        Rule::Break => break,
                         _ => {
                             log::error!("unknown switch default statemnt: {:?}", inner);
                         }
@@ -0,0 +1,375 @@
+use std::sync::Arc;
+
+use pest::iterators::Pair;
+
+use crate::ast::{
+    tstype::{MappedModifier, TsType, TupleElement, TypeMember, TypeParam},
+    value::Value,
+};
+
+use super::{
+    function::parse_param,
+    literal::{parse_bigint, parse_number, parse_string, unescape},
+    Rule,
+};
+
+/// Parses a name with optional type arguments, like the parents in `extends` and
+/// `implements`, which always refer to a declared type.
+pub fn parse_reference(reference: Pair<Rule>) -> TsType {
+    let mut inner = reference.into_inner();
+    let name = inner.next().unwrap().as_str().into();
+    let args = inner.next().map(parse_type_arguments).unwrap_or_default();
+
+    TsType::Reference { name, args }
+}
+
+pub fn parse_template_definition(tmp: Pair<Rule>) -> Vec<TypeParam> {
+    tmp.into_inner()
+        .map(|param| {
+            let mut inner = param.into_inner();
+            let name = inner.next().unwrap().as_str().into();
+            let mut constraint = None;
+            let mut default = None;
+
+            for part in inner {
+                let kind = Some(parse_ts_type(part.clone().into_inner().next().unwrap()));
+
+                match part.as_rule() {
+                    Rule::TemplateConstraint => constraint = kind,
+                    _ => default = kind,
+                }
+            }
+
+            TypeParam {
+                name,
+                constraint,
+                default,
+            }
+        })
+        .collect()
+}
+
+fn parse_type_arguments(args: Pair<Rule>) -> Vec<TsType> {
+    args.into_inner().map(parse_ts_type).collect()
+}
+
+fn parse_member_name(name: Pair<Rule>) -> String {
+    match name.as_rule() {
+        Rule::String => parse_string(name),
+        Rule::Number => parse_number(name).to_string(),
+        _ => name.as_str().into(),
+    }
+}
+
+/// Parses the parameters and return type of a signature into a [`TsType::Function`].
+fn parse_signature(signature: Pair<Rule>, is_constructor: bool) -> TsType {
+    let mut template_args = Vec::new();
+    let mut params = Vec::new();
+    let mut returns = TsType::Any;
+
+    for part in signature.into_inner() {
+        match part.as_rule() {
+            Rule::TemplateDefinition => template_args = parse_template_definition(part),
+            Rule::ParamList => params = part.into_inner().map(parse_param).collect(),
+            Rule::FunctionDefinition => {
+                for p in part.into_inner() {
+                    match p.as_rule() {
+                        Rule::ParamList => params = p.into_inner().map(parse_param).collect(),
+                        Rule::ReturnType => {
+                            returns = parse_return_type(p.into_inner().next().unwrap())
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Rule::TypePredicate | Rule::TsType => returns = parse_return_type(part),
+            _ => {}
+        }
+    }
+
+    TsType::Function {
+        is_constructor,
+        template_args,
+        params,
+        returns: Box::new(returns),
+    }
+}
+
+/// Parses what a `ReturnType` contains, which might also be a type predicate.
+pub fn parse_return_type(inner: Pair<Rule>) -> TsType {
+    match inner.as_rule() {
+        Rule::TypePredicate => {
+            let mut asserts = false;
+            let mut name = String::new();
+            let mut kind = None;
+
+            for part in inner.into_inner() {
+                match part.as_rule() {
+                    Rule::Asserts => asserts = true,
+                    Rule::Name => name = part.as_str().into(),
+                    Rule::TsType => kind = Some(Box::new(parse_ts_type(part))),
+                    _ => {}
+                }
+            }
+
+            TsType::Predicate {
+                name,
+                asserts,
+                kind,
+            }
+        }
+        _ => parse_ts_type(inner),
+    }
+}
+
+fn parse_member(member: Pair<Rule>) -> TypeMember {
+    let rule = member.as_rule();
+
+    match rule {
+        Rule::TypeCallSignature => TypeMember::Call(parse_signature(member, false)),
+        Rule::TypeConstructSignature => TypeMember::Construct(parse_signature(member, true)),
+        Rule::TypeIndexSignature => {
+            let mut inner = member.into_inner().peekable();
+            let is_readonly = inner.next_if(|p| p.as_rule() == Rule::Readonly).is_some();
+            let name = inner.next().unwrap().as_str().into();
+            let key = parse_ts_type(inner.next().unwrap());
+            let kind = parse_ts_type(inner.next().unwrap());
+
+            TypeMember::Index {
+                name,
+                is_readonly,
+                key,
+                kind,
+            }
+        }
+        _ => {
+            let mut inner = member.clone().into_inner().peekable();
+            let is_readonly = inner.next_if(|p| p.as_rule() == Rule::Readonly).is_some();
+            let name = parse_member_name(inner.next().unwrap());
+            let is_optional = inner.next_if(|p| p.as_rule() == Rule::Optional).is_some();
+
+            if rule == Rule::TypeMethod {
+                TypeMember::Method {
+                    name,
+                    is_optional,
+                    kind: parse_signature(member, false),
+                }
+            } else {
+                TypeMember::Property {
+                    name,
+                    is_optional,
+                    is_readonly,
+                    kind: inner.next().map(parse_ts_type),
+                }
+            }
+        }
+    }
+}
+
+fn parse_mapped_modifier(modifier: Pair<Rule>) -> MappedModifier {
+    if modifier.as_str().starts_with('-') {
+        MappedModifier::Remove
+    } else {
+        MappedModifier::Add
+    }
+}
+
+fn parse_primary(primary: Pair<Rule>) -> TsType {
+    match primary.as_rule() {
+        Rule::TsType => parse_ts_type(primary),
+        Rule::TypeReference => {
+            let mut inner = primary.into_inner();
+            let name = inner.next().unwrap().as_str();
+
+            match inner.next() {
+                Some(args) => TsType::Reference {
+                    name: name.into(),
+                    args: parse_type_arguments(args),
+                },
+                None => name.into(),
+            }
+        }
+        Rule::LiteralType => {
+            let mut inner = primary.into_inner();
+            let literal = inner.next().unwrap();
+
+            let value = match literal.as_rule() {
+                Rule::String => Value::String(parse_string(literal)),
+                Rule::Boolean => Value::Boolean(literal.as_str() == "true"),
+                Rule::BigInt => Value::BigInt(parse_bigint(literal)),
+                Rule::Negative => Value::Number(-parse_number(inner.next().unwrap())),
+                _ => Value::Number(parse_number(literal)),
+            };
+
+            TsType::Literal(Arc::new(value))
+        }
+        Rule::TypeTemplate => {
+            let mut quasis = Vec::new();
+            let mut kinds = Vec::new();
+
+            for part in primary.into_inner() {
+                match part.as_rule() {
+                    Rule::TemplateChars => quasis.push(unescape(part.as_str())),
+                    _ => kinds.push(parse_ts_type(part.into_inner().next().unwrap())),
+                }
+            }
+
+            TsType::TemplateLiteral { quasis, kinds }
+        }
+        Rule::TypeQuery => {
+            let mut inner = primary.into_inner().skip(1);
+            let path = inner.next().unwrap().as_str().split('.').map(String::from).collect();
+
+            TsType::TypeOf {
+                path,
+                args: inner.next().map(parse_type_arguments).unwrap_or_default(),
+            }
+        }
+        Rule::TypeInfer => TsType::Infer(primary.into_inner().nth(1).unwrap().as_str().into()),
+        Rule::TypeTuple => {
+            let elements = primary
+                .into_inner()
+                .map(|element| {
+                    let mut name = None;
+                    let mut is_optional = false;
+                    let mut is_rest = false;
+                    let mut kind = TsType::Any;
+
+                    for part in element.into_inner() {
+                        match part.as_rule() {
+                            Rule::ParamRest => is_rest = true,
+                            Rule::Name => name = Some(part.as_str().into()),
+                            Rule::Optional => is_optional = true,
+                            Rule::TsType => kind = parse_ts_type(part),
+                            _ => {}
+                        }
+                    }
+
+                    TupleElement {
+                        name,
+                        is_optional,
+                        is_rest,
+                        kind,
+                    }
+                })
+                .collect();
+
+            TsType::Tuple(elements)
+        }
+        Rule::TypeMapped => {
+            let mut name = String::new();
+            let mut readonly = None;
+            let mut optional = None;
+            let mut constraint = None;
+            let mut rename = None;
+            let mut kind = None;
+
+            for part in primary.into_inner() {
+                match part.as_rule() {
+                    Rule::MappedReadonly => readonly = Some(parse_mapped_modifier(part)),
+                    Rule::MappedOptional => optional = Some(parse_mapped_modifier(part)),
+                    Rule::Name => name = part.as_str().into(),
+                    Rule::MappedRename => {
+                        rename = Some(Box::new(parse_ts_type(part.into_inner().next().unwrap())))
+                    }
+                    // the type after `in` comes first, the type of the values last
+                    _ if constraint.is_none() => constraint = Some(parse_ts_type(part)),
+                    _ => kind = Some(Box::new(parse_ts_type(part))),
+                }
+            }
+
+            TsType::Mapped {
+                name,
+                constraint: Box::new(constraint.unwrap()),
+                rename,
+                readonly,
+                optional,
+                kind,
+            }
+        }
+        Rule::TypeLiteral => TsType::TypeLiteral(
+            primary
+                .into_inner()
+                .filter(|p| p.as_rule() != Rule::DocComment)
+                .map(parse_member)
+                .collect(),
+        ),
+        _ => unreachable!("unexpected type {:?}", primary.as_rule()),
+    }
+}
+
+fn parse_postfix(postfix: Pair<Rule>) -> TsType {
+    let mut inner = postfix.into_inner();
+    let mut kind = parse_primary(inner.next().unwrap());
+
+    for part in inner {
+        kind = match part.as_rule() {
+            Rule::ArrayType => TsType::Array(Box::new(kind)),
+            _ => TsType::Indexed {
+                object: Box::new(kind),
+                index: Box::new(parse_ts_type(part.into_inner().next().unwrap())),
+            },
+        };
+    }
+
+    kind
+}
+
+fn parse_operator(operator: Pair<Rule>) -> TsType {
+    let mut inner = operator.into_inner();
+    let first = inner.next().unwrap();
+
+    match first.as_rule() {
+        Rule::KeyOf => TsType::KeyOf(Box::new(parse_operator(inner.next().unwrap()))),
+        Rule::Readonly => TsType::Readonly(Box::new(parse_operator(inner.next().unwrap()))),
+        _ => parse_postfix(first),
+    }
+}
+
+fn parse_union(union: Pair<Rule>) -> TsType {
+    let mut kinds: Vec<TsType> = union
+        .into_inner()
+        .map(|intersection| {
+            let mut kinds: Vec<TsType> = intersection.into_inner().map(parse_operator).collect();
+
+            if kinds.len() == 1 {
+                kinds.pop().unwrap()
+            } else {
+                TsType::Intersection(kinds)
+            }
+        })
+        .collect();
+
+    if kinds.len() == 1 {
+        kinds.pop().unwrap()
+    } else {
+        TsType::Union(kinds)
+    }
+}
+
+pub fn parse_ts_type(kind: Pair<Rule>) -> TsType {
+    let mut inner = kind.into_inner();
+    let first = inner.next().unwrap();
+
+    match first.as_rule() {
+        Rule::FunctionType => parse_signature(first, false),
+        Rule::ConstructorType => parse_signature(first.into_inner().last().unwrap(), true),
+        _ => {
+            let kind = parse_union(first);
+
+            match inner.next() {
+                Some(conditional) => {
+                    let mut parts = conditional.into_inner();
+
+                    TsType::Conditional {
+                        check: Box::new(kind),
+                        extends: Box::new(parse_union(parts.next().unwrap())),
+                        then: Box::new(parse_ts_type(parts.next().unwrap())),
+                        otherwise: Box::new(parse_ts_type(parts.next().unwrap())),
+                    }
+                }
+                None => kind,
+            }
+        }
+    }
+}
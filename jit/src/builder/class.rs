@@ -0,0 +1,283 @@
+use std::sync::Arc;
+
+use llvm_sys::{core::LLVMBuildCall2, prelude::LLVMValueRef};
+use typescript_ast::ast::{
+    class::{Class, Method, MethodKind},
+    function::{Function, Param},
+    pattern::Pattern,
+    statement::Statement,
+    tstype::TsType,
+    value::Value,
+};
+
+use super::{
+    conditional::{build_null, build_undefined},
+    functions::{
+        build_arguments, build_call_value, build_function_value, compile_function,
+        compile_function_with,
+    },
+    object::build_set_attr,
+    pattern::build_call_extern,
+    statement::build_statements,
+    value::{build_get_attr, build_global_define, build_string, build_value},
+    Builder,
+};
+
+unsafe fn build_object_new(builder: &Builder) -> LLVMValueRef {
+    let object_new = builder.extern_functions.get("__object_new").unwrap();
+    let args: Vec<LLVMValueRef> = Vec::new();
+    LLVMBuildCall2(
+        builder.builder,
+        object_new.ft,
+        object_new.func,
+        args.as_ptr() as *mut LLVMValueRef,
+        args.len() as u32,
+        b"__object_new\0".as_ptr() as *const _,
+    )
+}
+
+unsafe fn build_object_spread(builder: &Builder, obj: LLVMValueRef, src: LLVMValueRef) {
+    let spread = builder.extern_functions.get("__object_spread").unwrap();
+    let args = vec![obj, src];
+    LLVMBuildCall2(
+        builder.builder,
+        spread.ft,
+        spread.func,
+        args.as_ptr() as *mut LLVMValueRef,
+        args.len() as u32,
+        b"__object_spread\0".as_ptr() as *const _,
+    );
+}
+
+/// The receiver of the currently compiled function, `null` on module level.
+pub unsafe fn build_this(builder: &mut Builder) -> LLVMValueRef {
+    match builder.this_ref {
+        Some(this) => {
+            let value_ref = builder.extern_functions.get("__value_ref").unwrap();
+            let args = vec![this];
+            LLVMBuildCall2(
+                builder.builder,
+                value_ref.ft,
+                value_ref.func,
+                args.as_ptr() as *mut LLVMValueRef,
+                args.len() as u32,
+                b"__this\0".as_ptr() as *const _,
+            )
+        }
+        None => build_null(builder),
+    }
+}
+
+/// The class the currently compiled class extends.
+pub unsafe fn build_super_class(builder: &mut Builder) -> LLVMValueRef {
+    match builder.super_class.clone() {
+        Some(class) => build_value(builder, class),
+        None => build_null(builder),
+    }
+}
+
+pub unsafe fn build_super_constructor(builder: &mut Builder) -> LLVMValueRef {
+    let class_ref = build_super_class(builder);
+    build_get_attr(builder, class_ref, "constructor")
+}
+
+pub unsafe fn build_super_method(builder: &mut Builder, name: &str) -> LLVMValueRef {
+    let class_ref = build_super_class(builder);
+    let prototype_ref = build_get_attr(builder, class_ref, "prototype");
+    build_get_attr(builder, prototype_ref, name)
+}
+
+/// Assigns the attribute defaults of the class which is currently compiled to `this`.
+pub unsafe fn build_field_initializers(builder: &mut Builder) {
+    for (name, value) in builder.field_initializers.clone() {
+        let this_ref = build_this(builder);
+        let name_ref = build_string(builder, &name);
+        let value_ref = build_value(builder, value);
+        build_set_attr(builder, this_ref, name_ref, value_ref);
+    }
+}
+
+/// Builds the constructor for classes which do not declare one. Derived classes hand
+/// all arguments over to the constructor of their parent, like `constructor(...args)`.
+fn default_constructor(derived: bool) -> Function {
+    let mut params = Vec::new();
+    let mut block = Vec::new();
+
+    if derived {
+        params.push(Param {
+            doc: None,
+            pattern: Pattern::Name("__args".into()),
+            is_rest: true,
+            is_optional: false,
+            kind: None,
+            default: None,
+        });
+
+        let args = Arc::new(Value::Identifier(vec!["__args".into()]));
+        block.push(Statement::Expression(Arc::new(Value::Call {
+            callee: Arc::new(Value::Super),
+            args: vec![Arc::new(Value::Spread(args))],
+            optional: false,
+        })));
+    }
+
+    Function {
+        doc: None,
+        name: Some("constructor".into()),
+        template_args: Vec::new(),
+        is_async: false,
+        is_generator: false,
+        is_declaration: false,
+        params,
+        returns: None,
+        block,
+    }
+}
+
+/// The name a method is stored under, accessors are kept apart from plain attributes.
+fn method_key(method: &Method) -> String {
+    let name = method.function.name.as_deref().unwrap_or_default();
+
+    match method.kind {
+        MethodKind::Method => name.to_string(),
+        MethodKind::Get => format!("@get:{}", name),
+        MethodKind::Set => format!("@set:{}", name),
+    }
+}
+
+/// Classes are objects holding their `constructor`, static members and a `prototype` with
+/// the methods, which get copied into every new instance. Derived classes keep their
+/// parent class in `@super`.
+pub unsafe fn build_class(builder: &mut Builder, class: &Class) -> LLVMValueRef {
+    let super_class = class
+        .extends
+        .as_ref()
+        .and_then(|e| match e {
+            // the type arguments of the parent only matter to the type checker
+            TsType::Reference { name, .. } => Some(name),
+            _ => None,
+        })
+        .map(|e| Arc::new(Value::Identifier(e.split('.').map(String::from).collect())));
+    let derived = super_class.is_some();
+    let old_super = std::mem::replace(&mut builder.super_class, super_class);
+
+    // parameter properties are assigned before the attribute defaults
+    let properties = class.constructor.iter().flat_map(|c| &c.properties).map(|p| {
+        let value = Arc::new(Value::Identifier(vec![p.name.clone()]));
+        (p.name.clone(), value)
+    });
+    let attributes = class
+        .attributes
+        .iter()
+        .filter(|a| !a.modifiers.is_static)
+        .filter_map(|a| Some((a.name.clone(), a.value.clone()?)));
+    let fields = properties.chain(attributes).collect();
+    let old_fields = std::mem::replace(&mut builder.field_initializers, fields);
+
+    let class_ref = build_object_new(builder);
+    let prototype_ref = build_object_new(builder);
+
+    if derived {
+        // static members are inherited, the parent's constructor and prototype get
+        // replaced below
+        let parent_ref = build_super_class(builder);
+        build_object_spread(builder, class_ref, parent_ref);
+
+        let parent_ref = build_super_class(builder);
+        let name_ref = build_string(builder, "@super");
+        build_set_attr(builder, class_ref, name_ref, parent_ref);
+
+        let parent_ref = build_super_class(builder);
+        let parent_ref = build_get_attr(builder, parent_ref, "prototype");
+        build_object_spread(builder, prototype_ref, parent_ref);
+    }
+
+    for method in &class.methods {
+        if method.function.is_declaration {
+            continue;
+        }
+
+        let (func, _) = compile_function(builder, &method.function);
+        let name_ref = build_string(builder, &method_key(method));
+        let value_ref = build_function_value(builder, func);
+        let target_ref = if method.modifiers.is_static {
+            class_ref
+        } else {
+            prototype_ref
+        };
+        build_set_attr(builder, target_ref, name_ref, value_ref);
+    }
+
+    let default;
+    let constructor = match &class.constructor {
+        Some(constructor) => &constructor.function,
+        None => {
+            default = default_constructor(derived);
+            &default
+        }
+    };
+
+    // derived classes initialize their fields after calling `super(...)`
+    let (func, _) = compile_function_with(builder, constructor, |builder| {
+        if !derived {
+            build_field_initializers(builder);
+        }
+    });
+
+    let name_ref = build_string(builder, "constructor");
+    let value_ref = build_function_value(builder, func);
+    build_set_attr(builder, class_ref, name_ref, value_ref);
+
+    let name_ref = build_string(builder, "prototype");
+    build_set_attr(builder, class_ref, name_ref, prototype_ref);
+
+    builder.field_initializers = old_fields;
+
+    let name_ref = build_string(builder, &class.name);
+    let ret = build_global_define(builder, name_ref, class_ref, true);
+
+    // static members run once the class exists, with the class as `this`
+    let old_this = builder.this_ref.replace(class_ref);
+
+    for attribute in class.attributes.iter().filter(|a| a.modifiers.is_static) {
+        let value_ref = match &attribute.value {
+            Some(value) => build_value(builder, value.clone()),
+            None => build_undefined(builder),
+        };
+        let name_ref = build_string(builder, &attribute.name);
+        build_set_attr(builder, class_ref, name_ref, value_ref);
+    }
+
+    for block in &class.static_blocks {
+        build_statements(builder, block);
+    }
+
+    builder.this_ref = old_this;
+    builder.super_class = old_super;
+
+    ret
+}
+
+pub unsafe fn build_new(
+    builder: &mut Builder,
+    callee: &Arc<Value>,
+    args: &[Arc<Value>],
+) -> LLVMValueRef {
+    let class_ref = build_value(builder, callee.clone());
+    let instance_ref = build_object_new(builder);
+
+    let prototype_ref = build_get_attr(builder, class_ref, "prototype");
+    build_object_spread(builder, instance_ref, prototype_ref);
+
+    // `instanceof` follows the class of an instance
+    let name_ref = build_string(builder, "@class");
+    let value_ref = build_call_extern(builder, "__value_ref", vec![class_ref]);
+    build_set_attr(builder, instance_ref, name_ref, value_ref);
+
+    let constructor_ref = build_get_attr(builder, class_ref, "constructor");
+
+    let args_ref = build_arguments(builder, args);
+    build_call_value(builder, constructor_ref, instance_ref, args_ref);
+
+    instance_ref
+}
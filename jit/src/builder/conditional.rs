@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use llvm_sys::{
+    core::{
+        LLVMAddIncoming, LLVMAppendBasicBlock, LLVMBuildBr, LLVMBuildCall2, LLVMBuildCondBr,
+        LLVMBuildICmp, LLVMBuildPhi, LLVMConstInt, LLVMGetInsertBlock, LLVMInt8TypeInContext,
+        LLVMPositionBuilderAtEnd,
+    },
+    prelude::{LLVMBasicBlockRef, LLVMValueRef},
+    LLVMIntPredicate,
+};
+use typescript_ast::ast::{operation::Operation, value::Value};
+
+use super::{
+    value::{build_cmp, build_cmp_value, build_value},
+    Builder,
+};
+
+unsafe fn build_phi(
+    builder: &mut Builder,
+    mut values: Vec<LLVMValueRef>,
+    mut blocks: Vec<LLVMBasicBlockRef>,
+) -> LLVMValueRef {
+    let phi = LLVMBuildPhi(builder.builder, builder.p64t, b"phi\0".as_ptr() as _);
+    LLVMAddIncoming(
+        phi,
+        values.as_mut_ptr(),
+        blocks.as_mut_ptr(),
+        values.len() as _,
+    );
+
+    phi
+}
+
+pub unsafe fn build_null(builder: &mut Builder) -> LLVMValueRef {
+    let null = builder.extern_functions.get("__global_null").unwrap();
+    let args: Vec<LLVMValueRef> = Vec::new();
+    LLVMBuildCall2(
+        builder.builder,
+        null.ft,
+        null.func,
+        args.as_ptr() as _,
+        args.len() as u32,
+        b"__null\0".as_ptr() as *const _,
+    )
+}
+
+pub unsafe fn build_undefined(builder: &mut Builder) -> LLVMValueRef {
+    let undefined = builder.extern_functions.get("__global_undefined").unwrap();
+    let args: Vec<LLVMValueRef> = Vec::new();
+    LLVMBuildCall2(
+        builder.builder,
+        undefined.ft,
+        undefined.func,
+        args.as_ptr() as _,
+        args.len() as u32,
+        b"__undefined\0".as_ptr() as *const _,
+    )
+}
+
+pub unsafe fn build_is_nullish(builder: &mut Builder, val: LLVMValueRef) -> LLVMValueRef {
+    let cond = {
+        let is_nullish = builder.extern_functions.get("__is_nullish").unwrap();
+        let args: Vec<LLVMValueRef> = vec![val];
+        LLVMBuildCall2(
+            builder.builder,
+            is_nullish.ft,
+            is_nullish.func,
+            args.as_ptr() as _,
+            args.len() as u32,
+            b"__is_nullish\0".as_ptr() as *const _,
+        )
+    };
+
+    let one = LLVMConstInt(LLVMInt8TypeInContext(builder.context), 1, 0);
+    LLVMBuildICmp(
+        builder.builder,
+        LLVMIntPredicate::LLVMIntEQ,
+        cond,
+        one,
+        b"cmp\0".as_ptr() as _,
+    )
+}
+
+pub unsafe fn build_conditional(
+    builder: &mut Builder,
+    cond: &Arc<Value>,
+    then: &Arc<Value>,
+    els: &Arc<Value>,
+) -> LLVMValueRef {
+    let cond = build_cmp(builder, cond.clone());
+
+    let _then = LLVMAppendBasicBlock(builder.current_function, b"cond_then\0".as_ptr() as _);
+    let _else = LLVMAppendBasicBlock(builder.current_function, b"cond_else\0".as_ptr() as _);
+    let _merge = LLVMAppendBasicBlock(builder.current_function, b"cond_end\0".as_ptr() as _);
+
+    LLVMBuildCondBr(builder.builder, cond, _then, _else);
+
+    builder.current_block = _then;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    let then_v = build_value(builder, then.clone());
+    let then_end = LLVMGetInsertBlock(builder.builder);
+    LLVMBuildBr(builder.builder, _merge);
+
+    builder.current_block = _else;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    let else_v = build_value(builder, els.clone());
+    let else_end = LLVMGetInsertBlock(builder.builder);
+    LLVMBuildBr(builder.builder, _merge);
+
+    builder.current_block = _merge;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    build_phi(builder, vec![then_v, else_v], vec![then_end, else_end])
+}
+
+/// Evaluates `right` only if `left` doesn't decide `&&` or `||` already. Like in JS the
+/// result is one of the operands, not a boolean.
+pub unsafe fn build_logical(
+    builder: &mut Builder,
+    left: &Arc<Value>,
+    op: &Operation,
+    right: &Arc<Value>,
+) -> LLVMValueRef {
+    let left_v = build_value(builder, left.clone());
+    let cond = build_cmp_value(builder, left_v);
+    let left_end = LLVMGetInsertBlock(builder.builder);
+
+    let _right = LLVMAppendBasicBlock(builder.current_function, b"logical\0".as_ptr() as _);
+    let _merge = LLVMAppendBasicBlock(builder.current_function, b"logical_end\0".as_ptr() as _);
+
+    if matches!(op, Operation::And) {
+        LLVMBuildCondBr(builder.builder, cond, _right, _merge);
+    } else {
+        LLVMBuildCondBr(builder.builder, cond, _merge, _right);
+    }
+
+    builder.current_block = _right;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    let right_v = build_value(builder, right.clone());
+    let right_end = LLVMGetInsertBlock(builder.builder);
+    LLVMBuildBr(builder.builder, _merge);
+
+    builder.current_block = _merge;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    build_phi(builder, vec![left_v, right_v], vec![left_end, right_end])
+}
+
+pub unsafe fn build_nullish(
+    builder: &mut Builder,
+    left: &Arc<Value>,
+    right: &Arc<Value>,
+) -> LLVMValueRef {
+    let left_v = build_value(builder, left.clone());
+    build_nullish_value(builder, left_v, right)
+}
+
+/// Evaluates `right` only if the already built `left_v` is nullish. Also used for the
+/// default values of parameters and patterns.
+pub unsafe fn build_nullish_value(
+    builder: &mut Builder,
+    left_v: LLVMValueRef,
+    right: &Arc<Value>,
+) -> LLVMValueRef {
+    let cond = build_is_nullish(builder, left_v);
+    let left_end = LLVMGetInsertBlock(builder.builder);
+
+    let _right = LLVMAppendBasicBlock(builder.current_function, b"nullish\0".as_ptr() as _);
+    let _merge = LLVMAppendBasicBlock(builder.current_function, b"nullish_end\0".as_ptr() as _);
+
+    LLVMBuildCondBr(builder.builder, cond, _right, _merge);
+
+    builder.current_block = _right;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    let right_v = build_value(builder, right.clone());
+    let right_end = LLVMGetInsertBlock(builder.builder);
+    LLVMBuildBr(builder.builder, _merge);
+
+    builder.current_block = _merge;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    build_phi(builder, vec![left_v, right_v], vec![left_end, right_end])
+}
+
+/// Leaves the surrounding optional chain, if `val` is nullish.
+pub unsafe fn build_optional_link(builder: &mut Builder, val: LLVMValueRef) {
+    if let Some(exit) = builder.chain_exits.last().cloned() {
+        let cond = build_is_nullish(builder, val);
+        let next = LLVMAppendBasicBlock(builder.current_function, b"chain_next\0".as_ptr() as _);
+
+        LLVMBuildCondBr(builder.builder, cond, exit, next);
+
+        builder.current_block = next;
+        LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+    }
+}
+
+pub unsafe fn build_chain(builder: &mut Builder, chain: &Arc<Value>) -> LLVMValueRef {
+    let _nullish = LLVMAppendBasicBlock(builder.current_function, b"chain_nullish\0".as_ptr() as _);
+    let _merge = LLVMAppendBasicBlock(builder.current_function, b"chain_end\0".as_ptr() as _);
+
+    builder.chain_exits.push(_nullish);
+    let chain_v = build_value(builder, chain.clone());
+    builder.chain_exits.pop();
+
+    let chain_end = LLVMGetInsertBlock(builder.builder);
+    LLVMBuildBr(builder.builder, _merge);
+
+    builder.current_block = _nullish;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    let undefined_v = build_undefined(builder);
+    LLVMBuildBr(builder.builder, _merge);
+
+    builder.current_block = _merge;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    build_phi(builder, vec![chain_v, undefined_v], vec![chain_end, _nullish])
+}
@@ -0,0 +1,52 @@
+use std::{collections::HashMap, sync::Arc};
+
+use llvm_sys::prelude::LLVMValueRef;
+use typescript_ast::ast::{enumeration::Enum, value::Value};
+
+use super::{
+    object::build_set_attr,
+    pattern::build_call_extern,
+    value::{build_global_define, build_string, build_value},
+    Builder,
+};
+
+/// The value of a `const enum` member, which gets inlined instead of looked up.
+pub fn const_enum_member(builder: &Builder, path: &[String]) -> Option<Arc<Value>> {
+    match path {
+        [name, member] => builder.const_enums.get(name)?.get(member).cloned(),
+        _ => None,
+    }
+}
+
+/// Enums are objects with all members. Like `tsc` does, numeric members can also be
+/// looked up by their value, so `Color[Color.Red]` is `"Red"`.
+pub unsafe fn build_enum(builder: &mut Builder, enumeration: &Enum) -> LLVMValueRef {
+    if enumeration.is_const {
+        let members = enumeration
+            .members
+            .iter()
+            .map(|m| (m.name.clone(), m.value.clone()))
+            .collect::<HashMap<_, _>>();
+        builder.const_enums.insert(enumeration.name.clone(), members);
+
+        return 0 as _;
+    }
+
+    let obj_ref = build_call_extern(builder, "__object_new", Vec::new());
+
+    for member in &enumeration.members {
+        let value_ref = build_value(builder, member.value.clone());
+
+        if !matches!(&*member.value, Value::String(_)) {
+            let key_ref = build_call_extern(builder, "__value_ref", vec![value_ref]);
+            let name_ref = build_string(builder, &member.name);
+            build_set_attr(builder, obj_ref, key_ref, name_ref);
+        }
+
+        let name_ref = build_string(builder, &member.name);
+        build_set_attr(builder, obj_ref, name_ref, value_ref);
+    }
+
+    let name_ref = build_string(builder, &enumeration.name);
+    build_global_define(builder, name_ref, obj_ref, true)
+}
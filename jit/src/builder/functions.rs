@@ -1,65 +1,474 @@
-use std::ffi::CString;
+use std::{ffi::CString, sync::Arc};
 
 use llvm_sys::{
     core::{
-        LLVMAddFunction, LLVMAppendBasicBlockInContext, LLVMBuildCall2, LLVMBuildRet,
-        LLVMFunctionType, LLVMPositionBuilderAtEnd,
+        LLVMAddFunction, LLVMAddIncoming, LLVMAppendBasicBlock, LLVMAppendBasicBlockInContext,
+        LLVMBuildBr, LLVMBuildCall2, LLVMBuildCondBr, LLVMBuildICmp, LLVMBuildIntToPtr,
+        LLVMBuildPhi, LLVMBuildPtrToInt, LLVMBuildRet, LLVMConstInt, LLVMCountParams, LLVMFunctionType,
+        LLVMGetInsertBlock, LLVMGetParam,
+        LLVMInt64TypeInContext, LLVMPointerType, LLVMPositionBuilderAtEnd,
     },
-    prelude::LLVMValueRef,
+    prelude::{LLVMBasicBlockRef, LLVMTypeRef, LLVMValueRef},
+    LLVMIntPredicate,
 };
-use typescript_ast::ast;
+use typescript_ast::ast::{self, value::Value};
 
-use super::{statement::build_statements, Builder, InternFunction};
+use super::{
+    class::{
+        build_field_initializers, build_super_constructor, build_super_method, build_this,
+    },
+    conditional::{build_null, build_nullish_value, build_optional_link, build_undefined},
+    generator::{build_generator_begin, build_generator_end},
+    pattern::build_pattern,
+    statement::{build_hoisted_vars, build_scopes_release, build_statements},
+    value::{
+        build_array_items, build_get_attr, build_get_attr_value, build_global_define, build_string,
+        build_value,
+    },
+    Builder, InternFunction,
+};
 
-pub unsafe fn build_function(
+/// Where `return` jumps to, together with the returned values of every `return`.
+pub struct ReturnTarget {
+    block: LLVMBasicBlockRef,
+    values: Vec<LLVMValueRef>,
+    blocks: Vec<LLVMBasicBlockRef>,
+}
+
+/// Compiles the body of a function. Every function takes the object it is called on,
+/// `this`, an array with all arguments, so any number of arguments can be passed, and the
+/// scope it was defined in.
+pub unsafe fn compile_function(
     builder: &mut Builder,
     stmnt: &ast::function::Function,
-) -> LLVMValueRef {
+) -> (LLVMValueRef, LLVMTypeRef) {
+    compile_function_with(builder, stmnt, |_| {})
+}
+
+/// Like `compile_function`, but calls `prologue` after the parameters are bound.
+pub unsafe fn compile_function_with<F>(
+    builder: &mut Builder,
+    stmnt: &ast::function::Function,
+    prologue: F,
+) -> (LLVMValueRef, LLVMTypeRef)
+where
+    F: FnOnce(&mut Builder),
+{
     let cname = CString::new(stmnt.name.clone().unwrap_or("generic".into())).unwrap();
 
-    let mut args = Vec::new();
-    for _ in 0..stmnt.params.len() {
-        args.push(builder.p64t);
-    }
-    let func_t = LLVMFunctionType(builder.p64t, args.as_ptr() as _, args.len() as _, 0);
+    let func_t = function_type(builder);
     let func = LLVMAddFunction(builder.module, cname.as_ptr(), func_t);
 
+    let bb = LLVMAppendBasicBlockInContext(builder.context, func, cname.as_ptr());
+    let return_block = LLVMAppendBasicBlock(func, b"return\0".as_ptr() as _);
+
+    let old_function = builder.current_function;
+    let old_block = builder.current_block;
+    let old_this = builder.this_ref.replace(LLVMGetParam(func, 0));
+    let old_generator = builder.generator.take();
+    // loops around the function can't be left from inside of it
+    let old_jumps = std::mem::take(&mut builder.jump_targets);
+    let old_block_scopes = std::mem::take(&mut builder.block_scopes);
+    let old_return = builder.return_target.replace(ReturnTarget {
+        block: return_block,
+        values: Vec::new(),
+        blocks: Vec::new(),
+    });
+    builder.current_function = func;
+    builder.current_block = bb;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    // every call gets its own scope for the parameters and local variables
+    let scope_ref = {
+        let ex = builder.extern_functions.get("__scope_new").unwrap();
+        let args = vec![LLVMGetParam(func, 2)];
+        LLVMBuildCall2(
+            builder.builder,
+            ex.ft,
+            ex.func,
+            args.as_ptr() as _,
+            args.len() as _,
+            b"__scope\0".as_ptr() as _,
+        )
+    };
+    let old_scope = std::mem::replace(&mut builder.scope_ref, scope_ref);
+
+    let args_ref = LLVMGetParam(func, 1);
+    for (i, param) in stmnt.params.iter().enumerate() {
+        let idx_ref = build_value(builder, Arc::new(Value::Number(i as f64)));
+        let value_ref = if param.is_rest {
+            let ex = builder.extern_functions.get("__array_rest").unwrap();
+            let args = vec![args_ref, idx_ref];
+            LLVMBuildCall2(
+                builder.builder,
+                ex.ft,
+                ex.func,
+                args.as_ptr() as _,
+                args.len() as _,
+                b"__rest\0".as_ptr() as _,
+            )
+        } else {
+            build_get_attr_value(builder, args_ref, idx_ref)
+        };
+        let value_ref = match &param.default {
+            Some(default) => build_nullish_value(builder, value_ref, default),
+            None => value_ref,
+        };
+        build_pattern(builder, &param.pattern, value_ref);
+    }
+
+    prologue(builder);
+
+    if stmnt.is_generator {
+        build_generator_begin(builder);
+    }
+
+    build_hoisted_vars(builder, &stmnt.block);
+    let mut last = build_statements(builder, &stmnt.block);
+
+    if last == 0 as _ {
+        last = build_undefined(builder);
+    }
+
+    // falling off the end returns the value of the last statement
+    let mut target = builder.return_target.take().unwrap();
+    target.values.push(last);
+    target.blocks.push(LLVMGetInsertBlock(builder.builder));
+    LLVMBuildBr(builder.builder, return_block);
+
+    builder.current_block = return_block;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    let ret = LLVMBuildPhi(builder.builder, builder.p64t, b"ret\0".as_ptr() as _);
+    LLVMAddIncoming(
+        ret,
+        target.values.as_mut_ptr(),
+        target.blocks.as_mut_ptr(),
+        target.values.len() as _,
+    );
+
+    if stmnt.is_generator {
+        // the scope of a generator is released once the generator gets destroyed
+        build_generator_end(builder, ret);
+    } else {
+        build_scope_delete(builder, scope_ref);
+        LLVMBuildRet(builder.builder, ret);
+    }
+
+    builder.current_function = old_function;
+    builder.current_block = old_block;
+    builder.this_ref = old_this;
+    builder.generator = old_generator;
+    builder.jump_targets = old_jumps;
+    builder.block_scopes = old_block_scopes;
+    builder.return_target = old_return;
+    builder.scope_ref = old_scope;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    (func, func_t)
+}
+
+/// Releases the scope of a function call. Functions defined within keep it alive.
+pub unsafe fn build_scope_delete(builder: &mut Builder, scope_ref: LLVMValueRef) {
+    let ex = builder.extern_functions.get("__scope_delete").unwrap();
+    let args = vec![scope_ref];
+    LLVMBuildCall2(
+        builder.builder,
+        ex.ft,
+        ex.func,
+        args.as_ptr() as _,
+        args.len() as _,
+        b"__scope_delete\0".as_ptr() as _,
+    );
+}
+
+/// Leaves the current function with `value`. On module level only the value is evaluated.
+pub unsafe fn build_return(builder: &mut Builder, value: &Arc<Value>) {
+    let value_ref = build_value(builder, value.clone());
+
+    if builder.return_target.is_none() {
+        return;
+    }
+
+    // the scopes of the blocks around the `return` are left as well
+    build_scopes_release(builder, 0);
+
+    let target = builder.return_target.as_mut().unwrap();
+
+    target.values.push(value_ref);
+    target.blocks.push(LLVMGetInsertBlock(builder.builder));
+    LLVMBuildBr(builder.builder, target.block);
+
+    // everything after the `return` is unreachable
+    builder.current_block = LLVMAppendBasicBlock(builder.current_function, b"after_return\0".as_ptr() as _);
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+}
+
+pub unsafe fn build_function(
+    builder: &mut Builder,
+    stmnt: &ast::function::Function,
+) -> LLVMValueRef {
+    // overload signatures only describe the implementation which follows
+    if stmnt.is_declaration {
+        return 0 as _;
+    }
+
+    let (func, func_t) = compile_function(builder, stmnt);
+
     if let Some(name) = stmnt.name.as_ref() {
+        // declared functions are values too, e.g. `const fns = [double]`
+        let name_ref = build_string(builder, name);
+        let value_ref = build_function_value(builder, func);
+        build_global_define(builder, name_ref, value_ref, true);
+
+        // only functions of the module can be called directly, the others need the
+        // scope of the call they were defined in
+        if builder.scope_ref != builder.namespace_ptr {
+            return func;
+        }
+
         builder.function_cache.insert(
             name.clone(),
             InternFunction {
                 func,
                 ft: func_t,
-                name: cname.clone(),
+                name: CString::new(name.as_str()).unwrap(),
             },
         );
     }
 
-    let bb = LLVMAppendBasicBlockInContext(builder.context, func, cname.as_ptr());
+    func
+}
 
-    let old_block = builder.current_block;
-    builder.current_block = bb;
+/// The type of all compiled functions: `fn(this, arguments, scope) -> value`. Native
+/// methods only take `this` and the arguments.
+pub unsafe fn function_type(builder: &Builder) -> LLVMTypeRef {
+    let mut args = vec![builder.p64t, builder.p64t, builder.p64t];
+    LLVMFunctionType(builder.p64t, args.as_mut_ptr(), args.len() as _, 0)
+}
+
+/// Collects the arguments of a call into an array, spread arguments get expanded.
+pub unsafe fn build_arguments(builder: &mut Builder, args: &[Arc<Value>]) -> LLVMValueRef {
+    build_array_items(builder, args)
+}
+
+/// Wraps a compiled function into a runtime value.
+pub unsafe fn build_function_value(builder: &mut Builder, func: LLVMValueRef) -> LLVMValueRef {
+    let addr = LLVMBuildPtrToInt(
+        builder.builder,
+        func,
+        LLVMInt64TypeInContext(builder.context),
+        b"func_to_var\0".as_ptr() as *const _,
+    );
+
+    let function_new = builder.extern_functions.get("__function_new").unwrap();
+    let args = vec![addr, builder.scope_ref];
+    LLVMBuildCall2(
+        builder.builder,
+        function_new.ft,
+        function_new.func,
+        args.as_ptr() as _,
+        args.len() as _,
+        b"__function_new\0".as_ptr() as _,
+    )
+}
+
+/// Calls a function value, which is only known at runtime.
+/// Calling anything which is not a function evaluates to `undefined`.
+pub unsafe fn build_call_value(
+    builder: &mut Builder,
+    func_ref: LLVMValueRef,
+    this_ref: LLVMValueRef,
+    args_ref: LLVMValueRef,
+) -> LLVMValueRef {
+    let addr = {
+        let ex = builder.extern_functions.get("__get_func_addr").unwrap();
+        let args = vec![func_ref];
+
+        LLVMBuildCall2(
+            builder.builder,
+            ex.ft,
+            ex.func,
+            args.as_ptr() as _,
+            args.len() as _,
+            b"get_func_addr\0".as_ptr() as _,
+        )
+    };
+
+    let call_blk = LLVMAppendBasicBlock(builder.current_function, b"call\0".as_ptr() as _);
+    let no_call_blk = LLVMAppendBasicBlock(builder.current_function, b"no_call\0".as_ptr() as _);
+    let merge = LLVMAppendBasicBlock(builder.current_function, b"call_end\0".as_ptr() as _);
+
+    let zero = LLVMConstInt(LLVMInt64TypeInContext(builder.context), 0, 0);
+    let cond = LLVMBuildICmp(
+        builder.builder,
+        LLVMIntPredicate::LLVMIntEQ,
+        addr,
+        zero,
+        b"cmp\0".as_ptr() as _,
+    );
+    LLVMBuildCondBr(builder.builder, cond, no_call_blk, call_blk);
+
+    builder.current_block = call_blk;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
-    let mut last = build_statements(builder, &stmnt.block);
+    let scope_ref = {
+        let ex = builder.extern_functions.get("__get_func_scope").unwrap();
+        let args = vec![func_ref];
 
-    if last == 0 as _ {
-        let null = builder.extern_functions.get("__global_null").unwrap();
-        let args: Vec<LLVMValueRef> = Vec::new();
-        last = LLVMBuildCall2(
+        LLVMBuildCall2(
             builder.builder,
-            null.ft,
-            null.func,
+            ex.ft,
+            ex.func,
             args.as_ptr() as _,
-            args.len() as u32,
-            b"__null\0".as_ptr() as *const _,
-        );
-    }
+            args.len() as _,
+            b"get_func_scope\0".as_ptr() as _,
+        )
+    };
+    let params = [this_ref, args_ref, scope_ref];
 
-    LLVMBuildRet(builder.builder, last);
+    let ft = function_type(builder);
+    let func_ptr = LLVMBuildIntToPtr(
+        builder.builder,
+        addr,
+        LLVMPointerType(ft, 0),
+        b"var_to_func\0".as_ptr() as *const _,
+    );
+    let ret = LLVMBuildCall2(
+        builder.builder,
+        ft,
+        func_ptr,
+        params.as_ptr() as _,
+        params.len() as _,
+        b"__call_value\0".as_ptr() as _,
+    );
+    LLVMBuildBr(builder.builder, merge);
 
-    builder.current_block = old_block;
+    builder.current_block = no_call_blk;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
-    func
+    let undefined = build_undefined(builder);
+    LLVMBuildBr(builder.builder, merge);
+
+    builder.current_block = merge;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    let phi = LLVMBuildPhi(builder.builder, builder.p64t, b"call_res\0".as_ptr() as _);
+    let mut values = vec![ret, undefined];
+    let mut blocks = vec![call_blk, no_call_blk];
+    LLVMAddIncoming(phi, values.as_mut_ptr(), blocks.as_mut_ptr(), values.len() as _);
+
+    phi
+}
+
+pub unsafe fn build_call(
+    builder: &mut Builder,
+    callee: &Arc<Value>,
+    args: &[Arc<Value>],
+    optional: bool,
+) -> LLVMValueRef {
+    // type assertions around a method must not lose the object it is called on
+    if let Value::As { value, .. } | Value::Satisfies { value, .. } | Value::NonNull(value) = &**callee {
+        return build_call(builder, value, args, optional);
+    }
+
+    if let Value::Identifier(identifier) = &**callee {
+        if identifier.len() == 1 {
+            if builder.extern_functions.contains_key(&identifier[0]) {
+                let mut params: Vec<LLVMValueRef> = Vec::new();
+
+                if args.iter().any(|a| matches!(**a, Value::Spread(_))) {
+                    // native functions take a fixed number of arguments
+                    let count = LLVMCountParams(builder.extern_functions[&identifier[0]].func);
+                    let args_ref = build_arguments(builder, args);
+
+                    for i in 0..count {
+                        let idx_ref = build_value(builder, Arc::new(Value::Number(i as f64)));
+                        params.push(build_get_attr_value(builder, args_ref, idx_ref));
+                    }
+                } else {
+                    for p in args {
+                        params.push(build_value(builder, p.clone()));
+                    }
+                }
+
+                let n = builder.extern_functions.get(&identifier[0]).unwrap();
+                return LLVMBuildCall2(
+                    builder.builder,
+                    n.ft,
+                    n.func,
+                    params.as_ptr() as _,
+                    params.len() as _,
+                    b"__call_extern\0".as_ptr() as _,
+                );
+            } else if builder.scope_ref == builder.namespace_ptr
+                && builder.function_cache.contains_key(&identifier[0])
+            {
+                // only the module scope itself can't shadow the function with a local, so
+                // only there it is called directly. plain function calls have no receiver
+                let params = vec![
+                    build_null(builder),
+                    build_arguments(builder, args),
+                    builder.namespace_ptr,
+                ];
+
+                let n = builder.function_cache.get(&identifier[0]).unwrap();
+                return LLVMBuildCall2(
+                    builder.builder,
+                    n.ft,
+                    n.func,
+                    params.as_ptr() as _,
+                    params.len() as _,
+                    b"__call_intern\0".as_ptr() as _,
+                );
+            }
+        }
+    }
+
+    // functions get the object they are called on handed over as first argument
+    let (this_ref, func_ref) = match &**callee {
+        Value::Super => {
+            let this_ref = build_this(builder);
+            (this_ref, build_super_constructor(builder))
+        }
+        Value::Member {
+            object, property, ..
+        } if matches!(**object, Value::Super) => {
+            let this_ref = build_this(builder);
+            (this_ref, build_super_method(builder, property))
+        }
+        Value::Identifier(identifier) if identifier.len() > 1 => {
+            let (property, object) = identifier.split_last().unwrap();
+            let obj_ref = build_value(builder, Arc::new(Value::Identifier(object.to_vec())));
+
+            (obj_ref, build_get_attr(builder, obj_ref, property))
+        }
+        Value::Member {
+            object,
+            property,
+            optional,
+        } => {
+            let obj_ref = build_value(builder, object.clone());
+
+            if *optional {
+                build_optional_link(builder, obj_ref);
+            }
+
+            (obj_ref, build_get_attr(builder, obj_ref, property))
+        }
+        _ => (build_null(builder), build_value(builder, callee.clone())),
+    };
+
+    if optional {
+        build_optional_link(builder, func_ref);
+    }
+
+    let args_ref = build_arguments(builder, args);
+    let ret = build_call_value(builder, func_ref, this_ref, args_ref);
+
+    if matches!(**callee, Value::Super) {
+        build_field_initializers(builder);
+    }
+
+    ret
 }
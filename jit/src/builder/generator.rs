@@ -0,0 +1,246 @@
+use std::{ffi::CString, sync::Arc};
+
+use llvm_sys::{
+    core::{
+        LLVMAddAttributeAtIndex, LLVMAddCase, LLVMAddFunction, LLVMAppendBasicBlock,
+        LLVMBuildBr, LLVMBuildCall2, LLVMBuildCondBr, LLVMBuildICmp, LLVMBuildRet, LLVMBuildSwitch, LLVMBuildUnreachable,
+        LLVMConstInt, LLVMConstNull, LLVMCreateStringAttribute, LLVMFunctionType,
+        LLVMGetNamedFunction, LLVMInt1TypeInContext, LLVMInt32TypeInContext,
+        LLVMInt64TypeInContext, LLVMInt8TypeInContext, LLVMPointerType, LLVMPositionBuilderAtEnd,
+        LLVMTokenTypeInContext,
+    },
+    prelude::{LLVMBasicBlockRef, LLVMTypeRef, LLVMValueRef},
+    LLVMAttributeFunctionIndex, LLVMIntPredicate,
+};
+use typescript_ast::ast::value::Value;
+
+use super::{
+    conditional::build_undefined,
+    value::{build_get_attr, build_value},
+    Builder,
+};
+
+/// The parts of a generator function, every `yield` needs to suspend it.
+#[derive(Clone, Copy)]
+pub struct GeneratorState {
+    gen: LLVMValueRef,
+    cleanup: LLVMBasicBlockRef,
+    suspend: LLVMBasicBlockRef,
+}
+
+unsafe fn i8p(builder: &Builder) -> LLVMTypeRef {
+    LLVMPointerType(LLVMInt8TypeInContext(builder.context), 0)
+}
+
+/// Calls one of the `llvm.coro.*` intrinsics.
+unsafe fn build_intrinsic(
+    builder: &mut Builder,
+    name: &str,
+    ret: LLVMTypeRef,
+    mut args: Vec<(LLVMTypeRef, LLVMValueRef)>,
+) -> LLVMValueRef {
+    let cname = CString::new(name).unwrap();
+    let mut types: Vec<_> = args.iter().map(|(t, _)| *t).collect();
+    let mut values: Vec<_> = args.drain(..).map(|(_, v)| v).collect();
+    let ft = LLVMFunctionType(ret, types.as_mut_ptr(), types.len() as _, 0);
+
+    let mut func = LLVMGetNamedFunction(builder.module, cname.as_ptr());
+    if func.is_null() {
+        func = LLVMAddFunction(builder.module, cname.as_ptr(), ft);
+    }
+
+    LLVMBuildCall2(
+        builder.builder,
+        ft,
+        func,
+        values.as_mut_ptr(),
+        values.len() as _,
+        b"\0".as_ptr() as _,
+    )
+}
+
+unsafe fn build_extern(builder: &mut Builder, name: &str, args: Vec<LLVMValueRef>) -> LLVMValueRef {
+    let ex = builder.extern_functions.get(name).unwrap();
+
+    LLVMBuildCall2(
+        builder.builder,
+        ex.ft,
+        ex.func,
+        args.as_ptr() as _,
+        args.len() as _,
+        b"\0".as_ptr() as _,
+    )
+}
+
+/// Suspends the generator. Execution continues in a new block once it gets resumed.
+unsafe fn build_suspend(builder: &mut Builder, state: GeneratorState, last: bool) {
+    let none = LLVMConstNull(LLVMTokenTypeInContext(builder.context));
+    let i1 = LLVMInt1TypeInContext(builder.context);
+    let res = build_intrinsic(
+        builder,
+        "llvm.coro.suspend",
+        LLVMInt8TypeInContext(builder.context),
+        vec![
+            (LLVMTokenTypeInContext(builder.context), none),
+            (i1, LLVMConstInt(i1, last as _, 0)),
+        ],
+    );
+
+    let resume = LLVMAppendBasicBlock(builder.current_function, b"resume\0".as_ptr() as _);
+    let switch = LLVMBuildSwitch(builder.builder, res, state.suspend, 2);
+    LLVMAddCase(switch, LLVMConstInt(LLVMInt8TypeInContext(builder.context), 0, 0), resume);
+    LLVMAddCase(
+        switch,
+        LLVMConstInt(LLVMInt8TypeInContext(builder.context), 1, 0),
+        state.cleanup,
+    );
+
+    builder.current_block = resume;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+}
+
+/// Turns the current function into a coroutine. Calling it creates the generator object,
+/// the body runs step by step with every call of `next`.
+pub unsafe fn build_generator_begin(builder: &mut Builder) {
+    let attr = LLVMCreateStringAttribute(
+        builder.context,
+        b"coroutine.presplit".as_ptr() as _,
+        18,
+        b"0".as_ptr() as _,
+        1,
+    );
+    LLVMAddAttributeAtIndex(builder.current_function, LLVMAttributeFunctionIndex, attr);
+
+    let i8p = i8p(builder);
+    let token = LLVMTokenTypeInContext(builder.context);
+    let null = LLVMConstNull(i8p);
+
+    let id = build_intrinsic(
+        builder,
+        "llvm.coro.id",
+        token,
+        vec![
+            (LLVMInt32TypeInContext(builder.context), LLVMConstInt(LLVMInt32TypeInContext(builder.context), 0, 0)),
+            (i8p, null),
+            (i8p, null),
+            (i8p, null),
+        ],
+    );
+    let size = build_intrinsic(
+        builder,
+        "llvm.coro.size.i64",
+        LLVMInt64TypeInContext(builder.context),
+        Vec::new(),
+    );
+    let mem = build_extern(builder, "__generator_alloc", vec![size]);
+    let handle = build_intrinsic(builder, "llvm.coro.begin", i8p, vec![(token, id), (i8p, mem)]);
+    let gen = build_extern(builder, "__generator_new", vec![handle]);
+
+    let entry = builder.current_block;
+    let cleanup = LLVMAppendBasicBlock(builder.current_function, b"cleanup\0".as_ptr() as _);
+    let suspend = LLVMAppendBasicBlock(builder.current_function, b"suspend\0".as_ptr() as _);
+
+    builder.current_block = cleanup;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+    let mem = build_intrinsic(builder, "llvm.coro.free", i8p, vec![(token, id), (i8p, handle)]);
+    build_extern(builder, "__generator_free", vec![mem]);
+    build_extern(builder, "__scope_delete", vec![builder.scope_ref]);
+    LLVMBuildBr(builder.builder, suspend);
+
+    builder.current_block = suspend;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+    let i1 = LLVMInt1TypeInContext(builder.context);
+    build_intrinsic(
+        builder,
+        "llvm.coro.end",
+        i1,
+        vec![(i8p, handle), (i1, LLVMConstInt(i1, 0, 0))],
+    );
+    LLVMBuildRet(builder.builder, gen);
+
+    builder.current_block = entry;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    let state = GeneratorState {
+        gen,
+        cleanup,
+        suspend,
+    };
+    builder.generator = Some(state);
+    builder.coroutines = true;
+
+    // the body does not start before the first `next`
+    build_suspend(builder, state, false);
+}
+
+/// Finishes the generator with the value `last`, instead of returning it.
+pub unsafe fn build_generator_end(builder: &mut Builder, last: LLVMValueRef) {
+    let state = builder.generator.unwrap();
+
+    build_extern(builder, "__generator_return", vec![state.gen, last]);
+    build_suspend(builder, state, true);
+
+    // a finished generator is never resumed again
+    LLVMBuildUnreachable(builder.builder);
+}
+
+pub unsafe fn build_yield(
+    builder: &mut Builder,
+    value: &Option<Arc<Value>>,
+    delegate: bool,
+) -> LLVMValueRef {
+    let state = match builder.generator {
+        Some(state) => state,
+        None => {
+            log::warn!("yield outside of a generator");
+            return build_undefined(builder);
+        }
+    };
+
+    let value_ref = match value {
+        Some(value) => build_value(builder, value.clone()),
+        None => build_undefined(builder),
+    };
+
+    if !delegate {
+        build_extern(builder, "__generator_yield", vec![state.gen, value_ref]);
+        build_suspend(builder, state, false);
+
+        return build_undefined(builder);
+    }
+
+    // `yield*` hands over every value of another iterable
+    build_extern(builder, "__generator_delegate", vec![state.gen, value_ref]);
+
+    let step_blk = LLVMAppendBasicBlock(builder.current_function, b"delegate_step\0".as_ptr() as _);
+    let yield_blk = LLVMAppendBasicBlock(builder.current_function, b"delegate_yield\0".as_ptr() as _);
+    let end_blk = LLVMAppendBasicBlock(builder.current_function, b"delegate_end\0".as_ptr() as _);
+    LLVMBuildBr(builder.builder, step_blk);
+
+    builder.current_block = step_blk;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+    let step = build_extern(builder, "__generator_delegate_next", vec![state.gen]);
+    let value_ref = build_get_attr(builder, step, "value");
+    let done_ref = build_get_attr(builder, step, "done");
+    let done = build_extern(builder, "__to_bool", vec![done_ref]);
+    let one = LLVMConstInt(LLVMInt8TypeInContext(builder.context), 1, 0);
+    let cond = LLVMBuildICmp(
+        builder.builder,
+        LLVMIntPredicate::LLVMIntEQ,
+        done,
+        one,
+        b"cmp\0".as_ptr() as _,
+    );
+    LLVMBuildCondBr(builder.builder, cond, end_blk, yield_blk);
+
+    builder.current_block = yield_blk;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+    build_extern(builder, "__generator_yield", vec![state.gen, value_ref]);
+    build_suspend(builder, state, false);
+    LLVMBuildBr(builder.builder, step_blk);
+
+    builder.current_block = end_blk;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    value_ref
+}
@@ -4,7 +4,7 @@ use llvm_sys::{
 };
 use typescript_ast::ast::ifelse::IfElse;
 
-use super::{Builder, value::build_cmp, statement::build_statements};
+use super::{Builder, value::build_cmp, statement::build_block};
 
 pub unsafe fn build_if(builder: &mut Builder, stmnt: &IfElse) -> LLVMValueRef {
     let ifblk = LLVMAppendBasicBlock(builder.current_function, b"if\0".as_ptr() as _);
@@ -45,7 +45,7 @@ pub unsafe fn build_if(builder: &mut Builder, stmnt: &IfElse) -> LLVMValueRef {
     builder.current_block = _then;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
-    let _if_v = build_statements(builder, &stmnt.block);
+    let _if_v = build_block(builder, &stmnt.block);
 
     LLVMBuildBr(builder.builder, _merge);
     // let mut _then = LLVMGetInsertBlock(self.builder);
@@ -69,7 +69,7 @@ pub unsafe fn build_if(builder: &mut Builder, stmnt: &IfElse) -> LLVMValueRef {
         builder.current_block = body;
         LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
-        let _elseif_v = build_statements(builder, &elseif.block);
+        let _elseif_v = build_block(builder, &elseif.block);
 
         LLVMBuildBr(builder.builder, _merge);
         // let mut _then = LLVMGetInsertBlock(self.builder);
@@ -80,7 +80,7 @@ pub unsafe fn build_if(builder: &mut Builder, stmnt: &IfElse) -> LLVMValueRef {
     builder.current_block = _else;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
-    let _else_v = build_statements(builder, &stmnt.els);
+    let _else_v = build_block(builder, &stmnt.els);
 
     LLVMBuildBr(builder.builder, _merge);
     // let mut _then = LLVMGetInsertBlock(self.builder);
@@ -7,21 +7,37 @@ use llvm_sys::{
         LLVMInt64TypeInContext, LLVMInt8TypeInContext, LLVMModuleCreateWithNameInContext,
         LLVMPointerType, LLVMPrintModuleToString, LLVMPositionBuilderAtEnd, LLVMAppendBasicBlockInContext, LLVMAddFunction, LLVMFunctionType, LLVMVoidTypeInContext, LLVMDoubleTypeInContext, LLVMCreateBuilderInContext, LLVMContextCreate,
     },
+    error::{LLVMDisposeErrorMessage, LLVMGetErrorMessage},
     execution_engine::{
         LLVMAddGlobalMapping, LLVMCreateExecutionEngineForModule, LLVMGetFunctionAddress,
     },
+    transforms::pass_builder::{
+        LLVMCreatePassBuilderOptions, LLVMDisposePassBuilderOptions, LLVMRunPasses,
+    },
     prelude::{
         LLVMBasicBlockRef, LLVMBuilderRef, LLVMContextRef, LLVMModuleRef, LLVMTypeRef, LLVMValueRef,
     },
 };
 use typescript_ast::ast;
 
-use crate::{error::JitError, stdlib::Array, Module, Value, callbacks};
+use crate::{error::JitError, stdlib::{self, Array}, Module, Value, callbacks};
 
-use self::statement::build_statement;
+use self::{
+    functions::ReturnTarget,
+    generator::GeneratorState,
+    repeat::JumpTarget,
+    statement::{build_hoisted_vars, build_statement},
+};
 
+mod class;
+mod conditional;
+mod enumeration;
 mod functions;
+mod generator;
 mod ifs;
+mod namespace;
+mod object;
+mod pattern;
 mod repeat;
 mod statement;
 mod switch;
@@ -55,6 +71,25 @@ pub struct Builder {
     f64t: LLVMTypeRef,
     p64t: LLVMTypeRef,
     namespace_ptr: LLVMValueRef,
+    /// The scope variables are looked up in, the module namespace or the call of the
+    /// function which is currently compiled.
+    scope_ref: LLVMValueRef,
+    chain_exits: Vec<LLVMBasicBlockRef>,
+    /// The scopes of the blocks around the statement which is built, a jump out of a
+    /// block releases them.
+    block_scopes: Vec<LLVMValueRef>,
+    jump_targets: Vec<JumpTarget>,
+    return_target: Option<ReturnTarget>,
+    /// The label in front of the loop or switch which is built next.
+    label: Option<String>,
+    this_ref: Option<LLVMValueRef>,
+    super_class: Option<Arc<ast::value::Value>>,
+    field_initializers: Vec<(String, Arc<ast::value::Value>)>,
+    const_enums: HashMap<String, HashMap<String, Arc<ast::value::Value>>>,
+    generator: Option<GeneratorState>,
+    coroutines: bool,
+    /// Mistakes found while building, they are reported once the whole module is built.
+    errors: Vec<String>,
     string_cache: HashMap<String, LLVMValueRef>,
     extern_functions: HashMap<String, ExternFunction>,
     function_cache: HashMap<String, InternFunction>,
@@ -74,6 +109,19 @@ impl Builder {
             f64t: 0 as _,
             p64t: 0 as _,
             namespace_ptr: 0 as _,
+            scope_ref: 0 as _,
+            chain_exits: Vec::new(),
+            block_scopes: Vec::new(),
+            jump_targets: Vec::new(),
+            return_target: None,
+            label: None,
+            this_ref: None,
+            super_class: None,
+            field_initializers: Vec::new(),
+            const_enums: HashMap::new(),
+            generator: None,
+            coroutines: false,
+            errors: Vec::new(),
             string_cache: HashMap::new(),
             extern_functions: HashMap::new(),
             function_cache: HashMap::new(),
@@ -151,25 +199,56 @@ impl Builder {
                 LLVMInt64TypeInContext(self.context),
                 b"__context\0".as_ptr() as *const _,
             );
+            self.scope_ref = self.namespace_ptr;
 
             self.add_fn("__global_null", callbacks::global_null as *mut _, 0);
+            self.add_fn("__global_undefined", callbacks::global_undefined as *mut _, 0);
             self.add_fn("__global_get", callbacks::global_get as *mut _, 2);
             self.add_fn("__global_set", callbacks::global_set as *mut _, 3);
+            self.add_fn("__global_define", callbacks::global_define as *mut _, 3);
+            self.add_fn("__scope_new", callbacks::scope_new as *mut _, 1);
+            self.add_fn("__scope_delete", callbacks::scope_delete as *mut _, 1);
+            self.add_fn("__get_func_scope", callbacks::get_func_scope as *mut _, 1);
             self.add_fn("__get_attr", callbacks::get_attr as *mut _, 2);
+            self.add_fn("__set_attr", callbacks::set_attr as *mut _, 3);
+            self.add_fn("__object_new", callbacks::object_new as *mut _, 0);
+            self.add_fn("__object_spread", callbacks::object_spread as *mut _, 2);
+            self.add_fn("__object_rest", callbacks::object_rest as *mut _, 2);
+            self.add_fn("__array_rest", callbacks::array_rest as *mut _, 2);
+            self.add_fn("__value_ref", callbacks::value_ref as *mut _, 1);
             self.add_fn("__value_delete", callbacks::value_delete as *mut _, 1);
             self.add_fn("__string_new", callbacks::string_new as *mut _, 0);
             self.add_fn("__string_copy", callbacks::string_copy as *mut _, 1);
+            self.add_fn("__string_concat", callbacks::string_concat as *mut _, 2);
+            self.add_fn("__to_string", callbacks::to_string as *mut _, 1);
             self.add_fn("__add", callbacks::add as *mut _, 2);
             self.add_fn("__sub", callbacks::sub as *mut _, 2);
             self.add_fn("__mul", callbacks::mul as *mut _, 2);
             self.add_fn("__div", callbacks::div as *mut _, 2);
             self.add_fn("__mod", callbacks::_mod as *mut _, 2);
+            self.add_fn("__pow", callbacks::pow as *mut _, 2);
+            self.add_fn("__bit_and", callbacks::bit_and as *mut _, 2);
+            self.add_fn("__bit_or", callbacks::bit_or as *mut _, 2);
+            self.add_fn("__bit_xor", callbacks::bit_xor as *mut _, 2);
+            self.add_fn("__shl", callbacks::shl as *mut _, 2);
+            self.add_fn("__shr", callbacks::shr as *mut _, 2);
+            self.add_fn("__ushr", callbacks::ushr as *mut _, 2);
             self.add_fn("__gt", callbacks::gt as *mut _, 2);
             self.add_fn("__gte", callbacks::gte as *mut _, 2);
             self.add_fn("__lt", callbacks::lt as *mut _, 2);
             self.add_fn("__lte", callbacks::lte as *mut _, 2);
             self.add_fn("__eq", callbacks::eq as *mut _, 2);
             self.add_fn("__neq", callbacks::neq as *mut _, 2);
+            self.add_fn("__strict_eq", callbacks::strict_eq as *mut _, 2);
+            self.add_fn("__strict_neq", callbacks::strict_neq as *mut _, 2);
+            self.add_fn("__instanceof", callbacks::instance_of as *mut _, 2);
+            self.add_fn("__in", callbacks::_in as *mut _, 2);
+            self.add_fn("__not", callbacks::not as *mut _, 1);
+            self.add_fn("__bit_not", callbacks::bit_not as *mut _, 1);
+            self.add_fn("__negative", callbacks::negative as *mut _, 1);
+            self.add_fn("__positive", callbacks::positive as *mut _, 1);
+            self.add_fn("__typeof", callbacks::type_of as *mut _, 1);
+            self.add_fn("__delete_attr", callbacks::delete_attr as *mut _, 2);
             self.add_fn("__and", callbacks::and as *mut _, 2);
             self.add_fn("__or", callbacks::or as *mut _, 2);
 
@@ -187,9 +266,17 @@ impl Builder {
 
                 self.add_fn_with("__to_bool", ret, args, callbacks::to_bool as _);
             }
+            {
+                let mut args = Vec::new();
+                args.push(self.p64t);
+                let ret = LLVMInt8TypeInContext(self.context);
+
+                self.add_fn_with("__is_nullish", ret, args, callbacks::is_nullish as _);
+            }
             {
                 let mut args = Vec::new();
                 args.push(LLVMPointerType(LLVMInt8TypeInContext(self.context), 0));
+                args.push(LLVMInt64TypeInContext(self.context));
 
                 self.add_fn_with(
                     "__string_from",
@@ -204,6 +291,36 @@ impl Builder {
 
                 self.add_fn_with("__number_new", self.p64t, args, callbacks::number_new as _);
             }
+            {
+                let mut args = Vec::new();
+                args.push(LLVMInt8TypeInContext(self.context));
+
+                self.add_fn_with("__boolean_new", self.p64t, args, callbacks::boolean_new as _);
+            }
+            {
+                let mut args = Vec::new();
+                args.push(LLVMInt64TypeInContext(self.context));
+                args.push(self.p64t);
+
+                self.add_fn_with("__function_new", self.p64t, args, callbacks::function_new as _);
+            }
+
+            {
+                let i8p = LLVMPointerType(LLVMInt8TypeInContext(self.context), 0);
+
+                self.add_fn_with(
+                    "__generator_alloc",
+                    i8p,
+                    vec![LLVMInt64TypeInContext(self.context)],
+                    stdlib::generator::generator_alloc as _,
+                );
+                self.add_fn_with("__generator_free", self.p64t, vec![i8p], stdlib::generator::generator_free as _);
+                self.add_fn_with("__generator_new", self.p64t, vec![i8p], stdlib::generator::generator_new as _);
+                self.add_fn("__generator_yield", stdlib::generator::generator_yield as _, 2);
+                self.add_fn("__generator_return", stdlib::generator::generator_return as _, 2);
+                self.add_fn("__generator_delegate", stdlib::generator::generator_delegate as _, 2);
+                self.add_fn("__generator_delegate_next", stdlib::generator::generator_delegate_next as _, 1);
+            }
 
             if self.stdlib {
                 Array::register(self);
@@ -236,10 +353,16 @@ impl Builder {
             self.current_block = bb;
         }
 
+        build_hoisted_vars(self, &module.statements);
+
         for stmnt in &module.statements {
             build_statement(self, stmnt);
         }
 
+        if !self.errors.is_empty() {
+            return Err(JitError::Compile(self.errors.join("\n")));
+        }
+
         unsafe {
             LLVMBuildRetVoid(self.builder);
 
@@ -270,6 +393,32 @@ impl Builder {
         Ok(())
     }
 
+    /// Splits generator functions into their resumable parts.
+    unsafe fn lower_coroutines(&mut self) -> Result<(), JitError> {
+        if !self.coroutines {
+            return Ok(());
+        }
+
+        let options = LLVMCreatePassBuilderOptions();
+        let error = LLVMRunPasses(
+            self.module,
+            b"function(coro-early),cgscc(coro-split),function(coro-cleanup)\0".as_ptr() as _,
+            std::ptr::null_mut(),
+            options,
+        );
+        LLVMDisposePassBuilderOptions(options);
+
+        if !error.is_null() {
+            let data = LLVMGetErrorMessage(error);
+            let cast = CStr::from_ptr(data);
+            let error = JitError::Coroutine(cast.to_str().unwrap().into());
+            LLVMDisposeErrorMessage(data);
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
     unsafe fn create_mapping(&mut self, module: &mut Module) {
         let start = SystemTime::now();
         let ns_ptr = Arc::into_raw(module.namespace.clone());
@@ -303,8 +452,18 @@ impl Builder {
         self.init();
         self.build_main(module)?;
 
+        let exports = module
+            .exports
+            .iter()
+            .filter_map(|(name, value)| match value {
+                ast::value::Value::Identifier(path) => Some((name.clone(), path.clone())),
+                _ => None,
+            })
+            .collect();
+
         let module_id = self.id.clone().unwrap_or(Vec::new());
         let mut module = Module::new(module_id);
+        module.exports = exports;
 
         unsafe {
             if let Some(ir) = &self.save_ir {
@@ -315,6 +474,8 @@ impl Builder {
                 dump.write(cast.to_bytes()).unwrap();
             }
 
+            self.lower_coroutines()?;
+
             let mut out = std::mem::zeroed();
 
             LLVMCreateExecutionEngineForModule(&mut module.ee, self.module, &mut out);
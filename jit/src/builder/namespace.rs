@@ -0,0 +1,55 @@
+use llvm_sys::prelude::LLVMValueRef;
+use typescript_ast::ast::{namespace::Namespace, statement::Statement};
+
+use super::{
+    object::build_set_attr,
+    pattern::build_call_extern,
+    statement::{build_hoisted_vars, build_statement},
+    value::{build_global_define, build_global_get, build_string},
+    Builder,
+};
+
+/// The names a statement defines at runtime, types and `const enum`s have no value.
+fn runtime_names(stmnt: &Statement) -> Vec<String> {
+    match stmnt {
+        Statement::Const(declarators)
+        | Statement::Let(declarators)
+        | Statement::Var(declarators) => declarators
+            .iter()
+            .flat_map(|d| d.pattern.names())
+            .map(String::from)
+            .collect(),
+        Statement::Function(f) if !f.is_declaration => f.name.iter().cloned().collect(),
+        Statement::Class(c) => vec![c.name.clone()],
+        Statement::Enum(e) if !e.is_const => vec![e.name.clone()],
+        Statement::Namespace(n) => vec![n.name.clone()],
+        _ => Vec::new(),
+    }
+}
+
+/// Runs the body of a namespace and collects its exported members in an object. A
+/// namespace declared more than once gets merged, like in `tsc`.
+pub unsafe fn build_namespace(builder: &mut Builder, namespace: &Namespace) -> LLVMValueRef {
+    build_hoisted_vars(builder, &namespace.statements);
+
+    for stmnt in &namespace.statements {
+        build_statement(builder, stmnt);
+    }
+
+    let defined: Vec<String> = namespace.statements.iter().flat_map(runtime_names).collect();
+
+    let obj_ref = build_call_extern(builder, "__object_new", Vec::new());
+    let name_ref = build_string(builder, &namespace.name);
+    let existing_ref = build_global_get(builder, name_ref, true);
+    build_call_extern(builder, "__object_spread", vec![obj_ref, existing_ref]);
+
+    for name in namespace.exports.iter().filter(|e| defined.contains(e)) {
+        let name_ref = build_string(builder, name);
+        let value_ref = build_global_get(builder, name_ref, true);
+        let name_ref = build_string(builder, name);
+        build_set_attr(builder, obj_ref, name_ref, value_ref);
+    }
+
+    let name_ref = build_string(builder, &namespace.name);
+    build_global_define(builder, name_ref, obj_ref, true)
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use llvm_sys::{core::LLVMBuildCall2, prelude::LLVMValueRef};
+use typescript_ast::ast::{
+    object::{Property, PropertyKey},
+    value::Value,
+};
+
+use super::{
+    functions::{build_function_value, compile_function},
+    value::{build_string, build_value},
+    Builder,
+};
+
+unsafe fn build_property_key(builder: &mut Builder, key: &PropertyKey) -> LLVMValueRef {
+    match key {
+        PropertyKey::Name(name) => build_string(builder, name),
+        PropertyKey::Computed(value) => build_value(builder, value.clone()),
+    }
+}
+
+pub unsafe fn build_set_attr(
+    builder: &Builder,
+    obj: LLVMValueRef,
+    name: LLVMValueRef,
+    value: LLVMValueRef,
+) -> LLVMValueRef {
+    let set_attr = builder.extern_functions.get("__set_attr").unwrap();
+    let args = vec![obj, name, value];
+    LLVMBuildCall2(
+        builder.builder,
+        set_attr.ft,
+        set_attr.func,
+        args.as_ptr() as *mut LLVMValueRef,
+        args.len() as u32,
+        b"__set_attr\0".as_ptr() as *const _,
+    )
+}
+
+pub unsafe fn build_object(builder: &mut Builder, properties: &Vec<Property>) -> LLVMValueRef {
+    let obj_ref = {
+        let object_new = builder.extern_functions.get("__object_new").unwrap();
+        let args: Vec<LLVMValueRef> = Vec::new();
+        LLVMBuildCall2(
+            builder.builder,
+            object_new.ft,
+            object_new.func,
+            args.as_ptr() as *mut LLVMValueRef,
+            args.len() as u32,
+            b"__object_new\0".as_ptr() as *const _,
+        )
+    };
+
+    for prop in properties {
+        match prop {
+            Property::Value { key, value } => {
+                let key_ref = build_property_key(builder, key);
+                let value_ref = build_value(builder, value.clone());
+                build_set_attr(builder, obj_ref, key_ref, value_ref);
+            }
+            Property::Shorthand(name) => {
+                let key_ref = build_string(builder, name);
+                let value = Arc::new(Value::Identifier(vec![name.clone()]));
+                let value_ref = build_value(builder, value);
+                build_set_attr(builder, obj_ref, key_ref, value_ref);
+            }
+            Property::Method { key, function } => {
+                let key_ref = build_property_key(builder, key);
+                let (func, _) = compile_function(builder, function);
+                let value_ref = build_function_value(builder, func);
+                build_set_attr(builder, obj_ref, key_ref, value_ref);
+            }
+            Property::Spread(value) => {
+                let value_ref = build_value(builder, value.clone());
+                let spread = builder.extern_functions.get("__object_spread").unwrap();
+                let args = vec![obj_ref, value_ref];
+                LLVMBuildCall2(
+                    builder.builder,
+                    spread.ft,
+                    spread.func,
+                    args.as_ptr() as *mut LLVMValueRef,
+                    args.len() as u32,
+                    b"__object_spread\0".as_ptr() as *const _,
+                );
+            }
+        }
+    }
+
+    obj_ref
+}
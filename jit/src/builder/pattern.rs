@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use llvm_sys::{core::LLVMBuildCall2, prelude::LLVMValueRef};
+use typescript_ast::ast::{object::PropertyKey, pattern::Pattern, value::Value};
+
+use super::{
+    conditional::build_nullish_value,
+    value::{
+        build_array, build_get_attr_value, build_global_define, build_global_set, build_store,
+        build_string, build_value,
+    },
+    Builder,
+};
+
+pub unsafe fn build_call_extern(builder: &mut Builder, name: &str, args: Vec<LLVMValueRef>) -> LLVMValueRef {
+    let ex = builder.extern_functions.get(name).unwrap();
+
+    LLVMBuildCall2(
+        builder.builder,
+        ex.ft,
+        ex.func,
+        args.as_ptr() as _,
+        args.len() as _,
+        b"__call_extern\0".as_ptr() as _,
+    )
+}
+
+unsafe fn build_part(
+    builder: &mut Builder,
+    pattern: &Pattern,
+    default: &Option<Arc<Value>>,
+    value_ref: LLVMValueRef,
+    define: bool,
+) {
+    let value_ref = match default {
+        Some(default) => build_nullish_value(builder, value_ref, default),
+        None => value_ref,
+    };
+
+    build_binding(builder, pattern, value_ref, define);
+}
+
+/// Binds `value_ref` to a pattern. Array patterns read the elements by index, object
+/// patterns read the named attributes of the value.
+pub unsafe fn build_pattern(builder: &mut Builder, pattern: &Pattern, value_ref: LLVMValueRef) {
+    build_binding(builder, pattern, value_ref, true);
+}
+
+/// Like `build_pattern`, but names only get declared in the current scope with `define`.
+/// Otherwise they are assigned to the scope they already live in.
+pub unsafe fn build_binding(
+    builder: &mut Builder,
+    pattern: &Pattern,
+    value_ref: LLVMValueRef,
+    define: bool,
+) {
+    match pattern {
+        Pattern::Name(name) => {
+            let name_ref = build_string(builder, name);
+
+            if define {
+                build_global_define(builder, name_ref, value_ref, true);
+            } else {
+                build_global_set(builder, name_ref, value_ref, true);
+            }
+        }
+        Pattern::Target(target) => {
+            build_store(builder, target, value_ref);
+        }
+        Pattern::Array { elements, rest } => {
+            for (i, element) in elements.iter().enumerate() {
+                if let Some(element) = element {
+                    let idx_ref = build_value(builder, Arc::new(Value::Number(i as f64)));
+                    let elem_ref = build_get_attr_value(builder, value_ref, idx_ref);
+                    build_part(builder, &element.pattern, &element.default, elem_ref, define);
+                }
+            }
+
+            if let Some(rest) = rest {
+                let start_ref = build_value(builder, Arc::new(Value::Number(elements.len() as f64)));
+                let rest_ref = build_call_extern(builder, "__array_rest", vec![value_ref, start_ref]);
+                build_binding(builder, rest, rest_ref, define);
+            }
+        }
+        Pattern::Object { properties, rest } => {
+            let mut keys = Vec::new();
+
+            for property in properties {
+                let key_ref = match &property.key {
+                    PropertyKey::Name(name) => build_string(builder, name),
+                    PropertyKey::Computed(key) => build_value(builder, key.clone()),
+                };
+                keys.push(key_ref);
+
+                let prop_ref = build_get_attr_value(builder, value_ref, key_ref);
+                build_part(builder, &property.pattern, &property.default, prop_ref, define);
+            }
+
+            if let Some(rest) = rest {
+                let keys_ref = build_array(builder, &keys);
+                let rest_ref = build_call_extern(builder, "__object_rest", vec![value_ref, keys_ref]);
+                build_binding(builder, rest, rest_ref, define);
+            }
+        }
+    }
+}
+
+/// A destructuring assignment evaluates to its right side.
+pub unsafe fn build_destructure(
+    builder: &mut Builder,
+    pattern: &Pattern,
+    value: &Arc<Value>,
+) -> LLVMValueRef {
+    let value_ref = build_value(builder, value.clone());
+    let ret_ref = build_call_extern(builder, "__value_ref", vec![value_ref]);
+    build_pattern(builder, pattern, value_ref);
+
+    ret_ref
+}
@@ -1,11 +1,167 @@
 use std::sync::Arc;
 
-use llvm_sys::{core::{LLVMAppendBasicBlock, LLVMBuildBr, LLVMPositionBuilderAtEnd, LLVMBuildCall2, LLVMPointerType, LLVMBuildIntToPtr, LLVMConstInt, LLVMInt8TypeInContext, LLVMBuildICmp, LLVMBuildCondBr, LLVMFunctionType}, prelude::LLVMValueRef, LLVMIntPredicate};
-use typescript_ast::ast::{statement::Statement, value::Value, repeat::Loop};
+use llvm_sys::{core::{LLVMAppendBasicBlock, LLVMBuildBr, LLVMPositionBuilderAtEnd, LLVMBuildCall2, LLVMConstInt, LLVMInt8TypeInContext, LLVMBuildICmp, LLVMBuildCondBr, LLVMAddIncoming, LLVMBuildPhi, LLVMGetInsertBlock}, prelude::{LLVMBasicBlockRef, LLVMValueRef}, LLVMIntPredicate};
+use typescript_ast::ast::{pattern::Pattern, statement::Statement, value::Value, repeat::Loop};
+
+use super::{
+    Builder,
+    functions::{build_call_value, build_scope_delete},
+    pattern::{build_call_extern, build_pattern},
+    statement::{
+        build_block, build_scope_close, build_scope_open, build_scopes_release, build_statement,
+        build_statements, declares_lexically,
+    },
+    value::{build_array, build_value, build_get_attr, build_cmp, build_global_define, build_global_get, build_string},
+};
+
+/// Where `break` and `continue` jump to from within a loop, a switch or a labeled statement.
+pub struct JumpTarget {
+    label: Option<String>,
+    break_block: LLVMBasicBlockRef,
+    /// Only loops can be continued.
+    continue_block: Option<LLVMBasicBlockRef>,
+    /// Labeled blocks can only be left with `break label`.
+    needs_label: bool,
+    /// The number of block scopes around the target, the ones opened after it are
+    /// released by a jump.
+    scope_depth: usize,
+}
+
+/// Makes `break` jump to `break_block`, the label in front of the statement is taken along.
+pub fn push_jump_target(builder: &mut Builder, break_block: LLVMBasicBlockRef, continue_block: Option<LLVMBasicBlockRef>) {
+    let label = builder.label.take();
+
+    builder.jump_targets.push(JumpTarget {
+        label,
+        break_block,
+        continue_block,
+        needs_label: false,
+        scope_depth: builder.block_scopes.len(),
+    });
+}
+
+/// Jumps to `block`, everything after the jump ends up in an unreachable block.
+unsafe fn build_jump(builder: &mut Builder, block: LLVMBasicBlockRef) {
+    LLVMBuildBr(builder.builder, block);
+
+    builder.current_block = LLVMAppendBasicBlock(builder.current_function, b"after_jump\0".as_ptr() as _);
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+}
+
+pub unsafe fn build_break(builder: &mut Builder, label: &Option<String>) {
+    let target = builder.jump_targets.iter().rev().find(|t| match label {
+        Some(_) => &t.label == label,
+        None => !t.needs_label,
+    });
+
+    match target.map(|t| (t.break_block, t.scope_depth)) {
+        Some((block, depth)) => {
+            build_scopes_release(builder, depth);
+            build_jump(builder, block);
+        }
+        None => builder.errors.push(match label {
+            Some(label) => format!("break to an unknown label: {}", label),
+            None => "break outside of a loop or switch".into(),
+        }),
+    }
+}
+
+pub unsafe fn build_continue(builder: &mut Builder, label: &Option<String>) {
+    let target = builder.jump_targets.iter().rev().find(|t| {
+        t.continue_block.is_some() && (label.is_none() || &t.label == label)
+    });
+
+    match target.and_then(|t| Some((t.continue_block?, t.scope_depth))) {
+        Some((block, depth)) => {
+            build_scopes_release(builder, depth);
+            build_jump(builder, block);
+        }
+        None => builder.errors.push(match label {
+            Some(label) => format!("continue to an unknown loop label: {}", label),
+            None => "continue outside of a loop".into(),
+        }),
+    }
+}
+
+pub unsafe fn build_labeled(builder: &mut Builder, label: &str, statement: &Statement) {
+    // loops and switches handle the label themselves, so `continue label` works
+    if matches!(statement, Statement::Loop(_) | Statement::Switch(_)) {
+        builder.label = Some(label.into());
+        build_statement(builder, statement);
+        builder.label = None;
+
+        return;
+    }
+
+    let end = LLVMAppendBasicBlock(builder.current_function, b"labeled_end\0".as_ptr() as _);
+
+    builder.jump_targets.push(JumpTarget {
+        label: Some(label.into()),
+        break_block: end,
+        continue_block: None,
+        needs_label: true,
+        scope_depth: builder.block_scopes.len(),
+    });
+    build_statement(builder, statement);
+    builder.jump_targets.pop();
+
+    LLVMBuildBr(builder.builder, end);
+
+    builder.current_block = end;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+}
+
+unsafe fn build_while(builder: &mut Builder, cond: &Arc<Value>, block: &Vec<Statement>) {
+    let while_cond = LLVMAppendBasicBlock(builder.current_function, b"while_cond\0".as_ptr() as _);
+    let while_block = LLVMAppendBasicBlock(builder.current_function, b"while_block\0".as_ptr() as _);
+    let while_end = LLVMAppendBasicBlock(builder.current_function, b"while_end\0".as_ptr() as _);
+
+    LLVMBuildBr(builder.builder, while_cond);
+
+    builder.current_block = while_cond;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    let cond = build_cmp(builder, cond.clone());
+    LLVMBuildCondBr(builder.builder, cond, while_block, while_end);
+
+    builder.current_block = while_block;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    push_jump_target(builder, while_end, Some(while_cond));
+    build_block(builder, block);
+    builder.jump_targets.pop();
+
+    LLVMBuildBr(builder.builder, while_cond);
+
+    builder.current_block = while_end;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+}
+
+unsafe fn build_do_while(builder: &mut Builder, block: &Vec<Statement>, cond: &Arc<Value>) {
+    let do_block = LLVMAppendBasicBlock(builder.current_function, b"do_block\0".as_ptr() as _);
+    let do_cond = LLVMAppendBasicBlock(builder.current_function, b"do_cond\0".as_ptr() as _);
+    let do_end = LLVMAppendBasicBlock(builder.current_function, b"do_end\0".as_ptr() as _);
+
+    LLVMBuildBr(builder.builder, do_block);
+
+    builder.current_block = do_block;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    push_jump_target(builder, do_end, Some(do_cond));
+    build_block(builder, block);
+    builder.jump_targets.pop();
 
-use super::{Builder, statement::build_statements, value::{build_value, build_get_attr, build_string, build_cmp, build_global_set}};
+    LLVMBuildBr(builder.builder, do_cond);
 
+    builder.current_block = do_cond;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    let cond = build_cmp(builder, cond.clone());
+    LLVMBuildCondBr(builder.builder, cond, do_block, do_end);
 
+    builder.current_block = do_end;
+    LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+}
 
 unsafe fn build_for(builder: &mut Builder, init: &Vec<Statement>, cond: &Arc<Value>, after: &Arc<Value>, block: &Vec<Statement>) {
     let for_loop = LLVMAppendBasicBlock(builder.current_function, b"for_init\0".as_ptr() as _);
@@ -19,35 +175,102 @@ unsafe fn build_for(builder: &mut Builder, init: &Vec<Statement>, cond: &Arc<Val
     builder.current_block = for_loop;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
+    // the `let`s of the head are copied into a new scope for every iteration, so
+    // closures keep the values of their iteration
+    let outer_ref = builder.scope_ref;
+    let scoped = declares_lexically(init);
+
+    if scoped {
+        builder.scope_ref = build_call_extern(builder, "__scope_new", vec![outer_ref]);
+    }
+
     build_statements(builder, init);
 
+    let init_scope_ref = builder.scope_ref;
+    let init_end = LLVMGetInsertBlock(builder.builder);
     LLVMBuildBr(builder.builder, for_cond);
 
     builder.current_block = for_cond;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
+    let scope_phi = if scoped {
+        let phi = LLVMBuildPhi(builder.builder, builder.p64t, b"for_scope\0".as_ptr() as _);
+        let mut values = vec![init_scope_ref];
+        let mut blocks = vec![init_end];
+        LLVMAddIncoming(phi, values.as_mut_ptr(), blocks.as_mut_ptr(), 1);
+
+        builder.scope_ref = phi;
+        builder.block_scopes.push(phi);
+        Some(phi)
+    } else {
+        None
+    };
+
     let cond = build_cmp(builder, cond.clone());
     let _if = LLVMBuildCondBr(builder.builder, cond, for_block, for_end);
 
     builder.current_block = for_block;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
-    build_statements(builder, block);
+    push_jump_target(builder, for_end, Some(for_after));
+    build_block(builder, block);
+    builder.jump_targets.pop();
 
     LLVMBuildBr(builder.builder, for_after);
 
     builder.current_block = for_after;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
+    if let Some(scope_ref) = scope_phi {
+        let next_ref = build_call_extern(builder, "__scope_new", vec![outer_ref]);
+
+        for name in lexical_names(init) {
+            let name_ref = build_string(builder, name);
+            let value_ref = build_global_get(builder, name_ref, true);
+
+            builder.scope_ref = next_ref;
+            let name_ref = build_string(builder, name);
+            build_global_define(builder, name_ref, value_ref, true);
+            builder.scope_ref = scope_ref;
+        }
+
+        build_scope_delete(builder, scope_ref);
+        builder.scope_ref = next_ref;
+    }
+
     build_value(builder, after.clone());
 
+    if let Some(phi) = scope_phi {
+        let mut values = vec![builder.scope_ref];
+        let mut blocks = vec![LLVMGetInsertBlock(builder.builder)];
+        LLVMAddIncoming(phi, values.as_mut_ptr(), blocks.as_mut_ptr(), 1);
+    }
+
     LLVMBuildBr(builder.builder, for_cond);
 
     builder.current_block = for_end;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
+
+    if let Some(scope_ref) = scope_phi {
+        builder.block_scopes.pop();
+        build_scope_delete(builder, scope_ref);
+        builder.scope_ref = outer_ref;
+    }
+}
+
+/// The names the `let`s and `const`s of `init` declare.
+fn lexical_names(init: &[Statement]) -> Vec<&str> {
+    init.iter()
+        .filter_map(|stmnt| match stmnt {
+            Statement::Let(declarators) | Statement::Const(declarators) => Some(declarators),
+            _ => None,
+        })
+        .flatten()
+        .flat_map(|declarator| declarator.pattern.names())
+        .collect()
 }
 
-unsafe fn build_for_of(builder: &mut Builder, name: &String, value: &Arc<Value>, block: &Vec<Statement>) {
+unsafe fn build_for_of(builder: &mut Builder, pattern: &Pattern, value: &Arc<Value>, block: &Vec<Statement>) {
     let for_loop = LLVMAppendBasicBlock(builder.current_function, b"for_of_init\0".as_ptr() as _);
     let for_cond = LLVMAppendBasicBlock(builder.current_function, b"for_of_cond\0".as_ptr() as _);
     let for_block = LLVMAppendBasicBlock(builder.current_function, b"for_of_block\0".as_ptr() as _);
@@ -58,90 +281,19 @@ unsafe fn build_for_of(builder: &mut Builder, name: &String, value: &Arc<Value>,
     builder.current_block = for_loop;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
-    let name_ref = build_string(builder, name);
     let value_ref = build_value(builder, value.clone());
+    // `@iterator` and `next` are called without arguments
+    let args_ref = build_array(builder, &Vec::new());
     let iter_ref = build_get_attr(builder, value_ref, "@iterator");
-    let iter_addr = {
-        let ex = builder.extern_functions.get("__get_func_addr").unwrap();
-        let args = vec![iter_ref];
-
-        LLVMBuildCall2(
-            builder.builder, 
-            ex.ft, 
-            ex.func, 
-            args.as_ptr() as _, 
-            args.len() as _, 
-            b"get_func_addr\0".as_ptr() as _
-        )
-    };
-    let iter_ref = {
-        let ret = builder.p64t;
-        let params = vec![builder.p64t];
-        let args: Vec<LLVMValueRef> = vec![value_ref];
-        let ft = LLVMFunctionType(ret, params.as_ptr() as *mut _, params.len() as u32, 0);
-        let ptr_type = LLVMPointerType(ft, 0);
-        // let ptr_type = LLVMFunctionType(ctx.llvm_ptr, args.as_ptr() as *mut _, args.len() as u32, 0);
-        let func_ptr = LLVMBuildIntToPtr(builder.builder, iter_addr, ptr_type, b"var_to_func\0".as_ptr() as *const _);
-        LLVMBuildCall2(
-            builder.builder, 
-            ft, 
-            func_ptr, 
-            args.as_ptr() as _, 
-            args.len() as _, 
-            b"iter_get\0".as_ptr() as _
-        )
-    };
-
+    let iter_ref = build_call_value(builder, iter_ref, value_ref, args_ref);
     let next_ref = build_get_attr(builder, iter_ref, "next");
-    let next_addr = {
-        let ex = builder.extern_functions.get("__get_func_addr").unwrap();
-        let args = vec![next_ref];
-
-        LLVMBuildCall2(
-            builder.builder, 
-            ex.ft, 
-            ex.func, 
-            args.as_ptr() as _, 
-            args.len() as _, 
-            b"get_func_addr\0".as_ptr() as _
-        )
-    };
-    let next_ref = {
-        let ret = builder.p64t;
-        let args = vec![builder.p64t];
-        let ft = LLVMFunctionType(ret, args.as_ptr() as *mut _, args.len() as u32, 0);
-        let ptr_type = LLVMPointerType(ft, 0);
-        // let ptr_type = LLVMFunctionType(ctx.llvm_ptr, args.as_ptr() as *mut _, args.len() as u32, 0);
-        let func_ptr = LLVMBuildIntToPtr(builder.builder, next_addr, ptr_type, b"var_to_func\0".as_ptr() as *const _);
-        func_ptr
-        // LLVMBuildCall2(
-        //     self.builder, 
-        //     ft, 
-        //     func_ptr, 
-        //     args.as_ptr() as _, 
-        //     0, 
-        //     b"get_next\0".as_ptr() as _
-        // )
-    };
 
     LLVMBuildBr(builder.builder, for_cond);
 
     builder.current_block = for_cond;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
-    let step_ref = {
-        let params = vec![builder.p64t];
-        let args: Vec<LLVMValueRef> = vec![iter_ref];
-        let ft = LLVMFunctionType(builder.p64t, params.as_ptr() as *mut _, params.len() as u32, 0);
-        LLVMBuildCall2(
-            builder.builder, 
-            ft, 
-            next_ref, 
-            args.as_ptr() as _, 
-            args.len() as _, 
-            b"next\0".as_ptr() as _
-        )
-    };
+    let step_ref = build_call_value(builder, next_ref, iter_ref, args_ref);
     let done_ref = build_get_attr(builder, step_ref, "done");
     let cond = {
         let null = builder.extern_functions.get("__to_bool").unwrap();
@@ -169,11 +321,17 @@ unsafe fn build_for_of(builder: &mut Builder, name: &String, value: &Arc<Value>,
     builder.current_block = for_block;
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
     
-    let value_ref = build_get_attr(builder, step_ref, "value");
-    build_global_set(builder, name_ref, value_ref, false);
+    // every iteration gets its own binding, so closures keep the value of theirs
+    push_jump_target(builder, for_end, Some(for_cond));
+    let parent_ref = build_scope_open(builder);
 
+    let value_ref = build_get_attr(builder, step_ref, "value");
+    build_pattern(builder, pattern, value_ref);
     build_statements(builder, block);
 
+    build_scope_close(builder, parent_ref);
+    builder.jump_targets.pop();
+
     LLVMBuildBr(builder.builder, for_cond);
 
     builder.current_block = for_end;
@@ -186,10 +344,15 @@ pub unsafe fn build_loop(builder: &mut Builder, stmnt: &Loop) {
         Loop::For{ init, cond, after, block } => {
             build_for(builder, init, cond, after, block);
         }
-        Loop::ForOf { name, value, block } => {
-            build_for_of(builder, name, value, block);
+        Loop::ForOf { pattern, value, block } => {
+            build_for_of(builder, pattern, value, block);
+        }
+        Loop::ForIn { pattern: _, value: _, block: _ } => {}
+        Loop::While { cond, block } => {
+            build_while(builder, cond, block);
+        }
+        Loop::DoWhile { block, cond } => {
+            build_do_while(builder, block, cond);
         }
-        Loop::ForIn { name: _, value: _, block: _ } => {}
-        Loop::While { cond: _, block: _} => {}
     }
 }
\ No newline at end of file
@@ -1,12 +1,18 @@
+use std::sync::Arc;
+
 use llvm_sys::prelude::LLVMValueRef;
 use typescript_ast::ast;
 
 use super::{
-    functions::build_function,
+    class::build_class,
+    enumeration::build_enum,
+    functions::{build_function, build_return, build_scope_delete},
     ifs::build_if,
-    repeat::build_loop,
+    namespace::build_namespace,
+    repeat::{build_break, build_continue, build_labeled, build_loop},
     switch::build_switch,
-    value::{build_string, build_value, build_global_set},
+    pattern::{build_binding, build_call_extern, build_pattern},
+    value::build_value,
     Builder,
 };
 
@@ -16,19 +22,36 @@ pub fn build_statement(
 ) -> LLVMValueRef {
     unsafe {
         match statement {
-            ast::statement::Statement::Const { name, value } => {
-                let name_ref = build_string(builder, name);
-                let value_ref = build_value(builder, value.clone());
-                build_global_set(builder, name_ref, value_ref, true)
-            }
-            ast::statement::Statement::Let { name, value } => {
-                let name_ref = build_string(builder, name);
-                let value_ref = build_value(builder, value.clone());
-                build_global_set(builder, name_ref, value_ref, true)
+            ast::statement::Statement::Const(declarators)
+            | ast::statement::Statement::Let(declarators)
+            | ast::statement::Statement::Var(declarators) => {
+                let is_var = matches!(statement, ast::statement::Statement::Var(_));
+
+                for declarator in declarators {
+                    let value = match &declarator.value {
+                        Some(value) => value.clone(),
+                        // a `var` already got its `undefined` when it was hoisted
+                        None if is_var => continue,
+                        None => Arc::new(ast::value::Value::Undefined),
+                    };
+                    let value_ref = build_value(builder, value);
+                    // a `var` lives in the function, blocks in between don't matter
+                    build_binding(builder, &declarator.pattern, value_ref, !is_var);
+                }
+
+                0 as _
             }
             ast::statement::Statement::Expression(call) => build_value(builder, call.clone()),
             ast::statement::Statement::Function(func) => build_function(builder, func),
-            ast::statement::Statement::Return(val) => build_value(builder, val.clone()),
+            ast::statement::Statement::Class(class) => build_class(builder, class),
+            ast::statement::Statement::Enum(enumeration) => build_enum(builder, enumeration),
+            ast::statement::Statement::Namespace(namespace) => build_namespace(builder, namespace),
+            ast::statement::Statement::Return(val) => {
+                build_return(builder, val);
+
+                0 as _
+            }
+            ast::statement::Statement::Block(block) => build_block(builder, block),
             ast::statement::Statement::If(ifelse) => build_if(builder, ifelse),
             ast::statement::Statement::Switch(switch) => build_switch(builder, switch),
             ast::statement::Statement::Loop(repeat) => {
@@ -36,6 +59,21 @@ pub fn build_statement(
 
                 0 as _
             }
+            ast::statement::Statement::Break(label) => {
+                build_break(builder, label);
+
+                0 as _
+            }
+            ast::statement::Statement::Continue(label) => {
+                build_continue(builder, label);
+
+                0 as _
+            }
+            ast::statement::Statement::Labeled { label, statement } => {
+                build_labeled(builder, label, statement);
+
+                0 as _
+            }
             _ => 0 as _,
         }
     }
@@ -43,7 +81,7 @@ pub fn build_statement(
 
 pub fn build_statements(
     builder: &mut Builder,
-    block: &Vec<ast::statement::Statement>,
+    block: &[ast::statement::Statement],
 ) -> LLVMValueRef {
     let mut ret = 0 as _;
 
@@ -53,3 +91,69 @@ pub fn build_statements(
 
     ret
 }
+
+/// Checks if `block` declares anything which only lives as long as the block does.
+pub fn declares_lexically(block: &[ast::statement::Statement]) -> bool {
+    block.iter().any(|stmnt| {
+        matches!(
+            stmnt,
+            ast::statement::Statement::Let(_)
+                | ast::statement::Statement::Const(_)
+                | ast::statement::Statement::Class(_)
+                | ast::statement::Statement::Function(_)
+        )
+    })
+}
+
+/// Builds the statements of a block. A block which declares `let`s, `const`s, classes or
+/// functions gets a scope of its own, so they hide the variables around the block.
+pub fn build_block(builder: &mut Builder, block: &[ast::statement::Statement]) -> LLVMValueRef {
+    if !declares_lexically(block) {
+        return build_statements(builder, block);
+    }
+
+    unsafe {
+        let parent_ref = build_scope_open(builder);
+        let ret = build_statements(builder, block);
+        build_scope_close(builder, parent_ref);
+
+        ret
+    }
+}
+
+/// Creates a scope within the current one and builds everything after it in there.
+/// Returns the scope to go back to.
+pub unsafe fn build_scope_open(builder: &mut Builder) -> LLVMValueRef {
+    let parent_ref = builder.scope_ref;
+    let scope_ref = build_call_extern(builder, "__scope_new", vec![parent_ref]);
+    builder.block_scopes.push(scope_ref);
+
+    std::mem::replace(&mut builder.scope_ref, scope_ref)
+}
+
+/// Releases the scope of `build_scope_open` and goes back to `parent_ref`.
+pub unsafe fn build_scope_close(builder: &mut Builder, parent_ref: LLVMValueRef) {
+    let scope_ref = builder.block_scopes.pop().unwrap();
+    build_scope_delete(builder, scope_ref);
+    builder.scope_ref = parent_ref;
+}
+
+/// Releases the scopes of all blocks a jump leaves, which are the ones opened after the
+/// first `depth`.
+pub unsafe fn build_scopes_release(builder: &mut Builder, depth: usize) {
+    for i in (depth..builder.block_scopes.len()).rev() {
+        let scope_ref = builder.block_scopes[i];
+        build_scope_delete(builder, scope_ref);
+    }
+}
+
+/// Defines the `var`s of a function body or module as `undefined` in its scope before any
+/// of its statements run, so they can be used before their declaration.
+pub fn build_hoisted_vars(builder: &mut Builder, block: &[ast::statement::Statement]) {
+    for name in ast::variable::var_names(block) {
+        unsafe {
+            let value_ref = build_value(builder, Arc::new(ast::value::Value::Undefined));
+            build_pattern(builder, &ast::pattern::Pattern::Name(name.into()), value_ref);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use llvm_sys::{core::{LLVMAppendBasicBlock, LLVMBuildCall2, LLVMInt8TypeInContext, LLVMConstInt, LLVMBuildICmp, LLVMBuildBr, LLVMPositionBuilderAtEnd, LLVMBuildCondBr}, prelude::LLVMValueRef, LLVMIntPredicate};
 use typescript_ast::ast::switch::Switch;
 
-use super::{Builder, statement::build_statements, value::build_value};
+use super::{Builder, pattern::build_call_extern, repeat::push_jump_target, statement::build_block, value::build_value};
 
 
 pub unsafe fn build_switch(builder: &mut Builder, switch: &Switch) -> LLVMValueRef {
@@ -28,6 +28,9 @@ pub unsafe fn build_switch(builder: &mut Builder, switch: &Switch) -> LLVMValueR
     let default = LLVMAppendBasicBlock(builder.current_function, b"default\0".as_ptr() as _);
     let _merge = LLVMAppendBasicBlock(builder.current_function, b"switch_end\0".as_ptr() as _);
 
+    // `break` leaves the switch, `continue` goes on to an enclosing loop
+    push_jump_target(builder, _merge, None);
+
     for i in 0..switch.branches.len() {
         let case = &switch.branches[i];
         let (check, body) = cases[i];
@@ -41,9 +44,11 @@ pub unsafe fn build_switch(builder: &mut Builder, switch: &Switch) -> LLVMValueR
         LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
         let cond = build_value(builder, case.expr.clone());
+        // `__eq` consumes its arguments, but every case compares against the same value
+        let exp_ref = build_call_extern(builder, "__value_ref", vec![exp]);
         let eq = {
             let eq = builder.extern_functions.get("__eq").unwrap();
-            let args: Vec<LLVMValueRef> = vec![exp, cond];
+            let args: Vec<LLVMValueRef> = vec![exp_ref, cond];
             LLVMBuildCall2(
                 builder.builder,
                 eq.ft,
@@ -79,7 +84,7 @@ pub unsafe fn build_switch(builder: &mut Builder, switch: &Switch) -> LLVMValueR
         builder.current_block = body;
         LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
-        build_statements(builder, &case.block);
+        build_block(builder, &case.block);
 
         LLVMBuildBr(builder.builder, _merge);
     }
@@ -88,10 +93,11 @@ pub unsafe fn build_switch(builder: &mut Builder, switch: &Switch) -> LLVMValueR
     LLVMPositionBuilderAtEnd(builder.builder, builder.current_block);
 
     if let Some(default) = &switch.default {
-        build_statements(builder, default);
+        build_block(builder, default);
     }
 
     LLVMBuildBr(builder.builder, _merge);
+    builder.jump_targets.pop();
     // let mut _else = LLVMGetInsertBlock(self.builder);
 
     builder.current_block = _merge;
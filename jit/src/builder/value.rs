@@ -1,15 +1,31 @@
-use std::{ffi::CString, sync::Arc};
+use std::sync::Arc;
 
 use llvm_sys::{
-    core::{LLVMBuildCall2, LLVMBuildGlobalStringPtr, LLVMConstReal, LLVMConstInt, LLVMInt8TypeInContext, LLVMBuildFAdd, LLVMBuildFSub, LLVMBuildICmp},
-    prelude::LLVMValueRef, LLVMIntPredicate,
+    core::{LLVMBuildCall2, LLVMConstReal, LLVMConstInt, LLVMInt8TypeInContext, LLVMBuildFAdd, LLVMBuildFSub, LLVMBuildICmp, LLVMAddGlobal, LLVMConstPointerCast, LLVMConstStringInContext, LLVMInt64TypeInContext, LLVMPointerType, LLVMSetGlobalConstant, LLVMSetInitializer, LLVMSetLinkage, LLVMTypeOf},
+    prelude::LLVMValueRef, LLVMIntPredicate, LLVMLinkage,
+};
+use typescript_ast::ast::{operation::{Operation, AssignOperation, UnaryOperation}, value::Value};
+
+use super::{
+    class::{build_new, build_super_class, build_this},
+    enumeration::const_enum_member,
+    conditional::{
+        build_chain, build_conditional, build_logical, build_null, build_nullish, build_optional_link,
+        build_undefined,
+    },
+    functions::{build_call, build_function_value, compile_function},
+    generator::build_yield,
+    object::{build_object, build_set_attr},
+    pattern::build_destructure,
+    Builder,
 };
-use typescript_ast::ast::{operation::{Operation, AssignOperation}, value::Value};
-
-use super::Builder;
 
 pub fn build_get_attr(builder: &mut Builder, obj: LLVMValueRef, name: &str) -> LLVMValueRef {
     let name_ref = build_string(builder, name);
+    build_get_attr_value(builder, obj, name_ref)
+}
+
+pub fn build_get_attr_value(builder: &Builder, obj: LLVMValueRef, name_ref: LLVMValueRef) -> LLVMValueRef {
     let ex = builder.extern_functions.get("__get_attr").unwrap();
     let args = vec![obj, name_ref];
     unsafe {
@@ -27,7 +43,7 @@ pub fn build_get_attr(builder: &mut Builder, obj: LLVMValueRef, name: &str) -> L
 pub fn build_global_get(builder: &Builder, name: LLVMValueRef, cleanup: bool) -> LLVMValueRef {
     let get_global = builder.extern_functions.get("__global_get").unwrap();
     let value_delete = builder.extern_functions.get("__value_delete").unwrap();
-    let args = vec![builder.namespace_ptr, name];
+    let args = vec![builder.scope_ref, name];
     let delete_args = vec![name];
 
     unsafe {
@@ -56,16 +72,26 @@ pub fn build_global_get(builder: &Builder, name: LLVMValueRef, cleanup: bool) ->
 }
 
 pub fn build_global_set(builder: &Builder, name: LLVMValueRef, value: LLVMValueRef, cleanup: bool) -> LLVMValueRef {
-    let global_set = builder.extern_functions.get("__global_set").unwrap();
+    build_variable_store(builder, "__global_set", name, value, cleanup)
+}
+
+/// Declares a variable in the current scope, unlike `build_global_set` which assigns
+/// to the scope the variable already lives in.
+pub fn build_global_define(builder: &Builder, name: LLVMValueRef, value: LLVMValueRef, cleanup: bool) -> LLVMValueRef {
+    build_variable_store(builder, "__global_define", name, value, cleanup)
+}
+
+fn build_variable_store(builder: &Builder, callback: &str, name: LLVMValueRef, value: LLVMValueRef, cleanup: bool) -> LLVMValueRef {
+    let store = builder.extern_functions.get(callback).unwrap();
     let value_delete = builder.extern_functions.get("__value_delete").unwrap();
-    let args = vec![builder.namespace_ptr, name, value];
+    let args = vec![builder.scope_ref, name, value];
     let delete_args = vec![name];
 
     unsafe {
         let ret = LLVMBuildCall2(
             builder.builder,
-            global_set.ft,
-            global_set.func,
+            store.ft,
+            store.func,
             args.as_ptr() as *mut LLVMValueRef,
             args.len() as u32,
             b"__global_set\0".as_ptr() as *const _,
@@ -87,9 +113,13 @@ pub fn build_global_set(builder: &Builder, name: LLVMValueRef, value: LLVMValueR
 }
 
 pub fn build_cmp(builder: &mut Builder, val: Arc<Value>) -> LLVMValueRef {
-    unsafe {
-        let cond = build_value(builder, val.clone());
+    let cond = unsafe { build_value(builder, val) };
+    build_cmp_value(builder, cond)
+}
 
+/// Converts the already built `cond` into an `i1` for branching.
+pub fn build_cmp_value(builder: &mut Builder, cond: LLVMValueRef) -> LLVMValueRef {
+    unsafe {
         let cond = {
             let null = builder.extern_functions.get("__to_bool").unwrap();
             let args: Vec<LLVMValueRef> = vec![cond];
@@ -114,6 +144,31 @@ pub fn build_cmp(builder: &mut Builder, val: Arc<Value>) -> LLVMValueRef {
     }
 }
 
+/// Builds an array literal, spread elements get expanded into the array.
+pub unsafe fn build_array_items(builder: &mut Builder, items: &[Arc<Value>]) -> LLVMValueRef {
+    let arr_ref = build_array(builder, &Vec::new());
+
+    for item in items {
+        let (name, value_ref) = match &**item {
+            Value::Spread(value) => ("__array_extend", build_value(builder, value.clone())),
+            _ => ("__array_push", build_value(builder, item.clone())),
+        };
+
+        let ex = builder.extern_functions.get(name).unwrap();
+        let args = vec![arr_ref, value_ref];
+        LLVMBuildCall2(
+            builder.builder,
+            ex.ft,
+            ex.func,
+            args.as_ptr() as *mut LLVMValueRef,
+            args.len() as u32,
+            b"__array_item\0".as_ptr() as *const _,
+        );
+    }
+
+    arr_ref
+}
+
 pub fn build_array(builder: &Builder, parts: &Vec<LLVMValueRef>) -> LLVMValueRef {
     let an_ref = unsafe {
         let an = builder.extern_functions.get("__array_new").unwrap();
@@ -156,24 +211,22 @@ pub fn build_string(builder: &mut Builder, s: &str) -> LLVMValueRef {
         let cstr = if let Some(cstr) = builder.string_cache.get(s) {
             *cstr
         } else {
-            let cs = CString::new(s).unwrap();
-            // let cstr = LLVMBuildGlobalString(
-            //     self.builder,
-            //     cs.as_ptr(),
-            //     b"__str\0".as_ptr() as *const _,
-            // );
-            let cstr = LLVMBuildGlobalStringPtr(
-                builder.builder,
-                cs.as_ptr(),
-                b"__str\0".as_ptr() as *const _,
-            );
+            // strings may contain NUL bytes, so the constant carries its own length
+            let data = LLVMConstStringInContext(builder.context, s.as_ptr() as _, s.len() as u32, 1);
+            let global = LLVMAddGlobal(builder.module, LLVMTypeOf(data), b"__str\0".as_ptr() as *const _);
+            LLVMSetInitializer(global, data);
+            LLVMSetGlobalConstant(global, 1);
+            LLVMSetLinkage(global, LLVMLinkage::LLVMPrivateLinkage);
+
+            let cstr = LLVMConstPointerCast(global, LLVMPointerType(LLVMInt8TypeInContext(builder.context), 0));
 
             builder.string_cache.insert(s.to_string(), cstr);
 
             cstr
         };
 
-        let args = vec![cstr];
+        let len = LLVMConstInt(LLVMInt64TypeInContext(builder.context), s.len() as u64, 0);
+        let args = vec![cstr, len];
         let string_from = builder.extern_functions.get("__string_from").unwrap();
         LLVMBuildCall2(
             builder.builder,
@@ -186,6 +239,35 @@ pub fn build_string(builder: &mut Builder, s: &str) -> LLVMValueRef {
     }
 }
 
+unsafe fn build_string_op(builder: &Builder, name: &str, args: Vec<LLVMValueRef>) -> LLVMValueRef {
+    let call = builder.extern_functions.get(name).unwrap();
+    LLVMBuildCall2(
+        builder.builder,
+        call.ft,
+        call.func,
+        args.as_ptr() as *mut LLVMValueRef,
+        args.len() as u32,
+        b"__string_op\0".as_ptr() as *const _,
+    )
+}
+
+pub unsafe fn build_template(builder: &mut Builder, quasis: &[String], exprs: &[Arc<Value>]) -> LLVMValueRef {
+    let mut result = build_string(builder, &quasis[0]);
+
+    for (expr, quasi) in exprs.iter().zip(&quasis[1..]) {
+        let expr_ref = build_value(builder, expr.clone());
+        let expr_ref = build_string_op(builder, "__to_string", vec![expr_ref]);
+        result = build_string_op(builder, "__string_concat", vec![result, expr_ref]);
+
+        if !quasi.is_empty() {
+            let quasi_ref = build_string(builder, quasi);
+            result = build_string_op(builder, "__string_concat", vec![result, quasi_ref]);
+        }
+    }
+
+    result
+}
+
 // fn build_op(
 //     builder: &mut Builder,
 //     op: &Operation,
@@ -217,8 +299,11 @@ pub fn build_generic_op(
         Operation::Sub => builder.extern_functions.get("__sub").unwrap(),
         Operation::Mul => builder.extern_functions.get("__mul").unwrap(),
         Operation::Div => builder.extern_functions.get("__div").unwrap(),
+        Operation::Pow => builder.extern_functions.get("__pow").unwrap(),
         Operation::Eq => builder.extern_functions.get("__eq").unwrap(),
         Operation::Ne => builder.extern_functions.get("__neq").unwrap(),
+        Operation::StrictEq => builder.extern_functions.get("__strict_eq").unwrap(),
+        Operation::StrictNe => builder.extern_functions.get("__strict_neq").unwrap(),
         Operation::Gt => builder.extern_functions.get("__gt").unwrap(),
         Operation::Gte => builder.extern_functions.get("__gte").unwrap(),
         Operation::Lt => builder.extern_functions.get("__lt").unwrap(),
@@ -226,6 +311,14 @@ pub fn build_generic_op(
         Operation::Mod => builder.extern_functions.get("__mod").unwrap(),
         Operation::And => builder.extern_functions.get("__and").unwrap(),
         Operation::Or => builder.extern_functions.get("__or").unwrap(),
+        Operation::BitAnd => builder.extern_functions.get("__bit_and").unwrap(),
+        Operation::BitOr => builder.extern_functions.get("__bit_or").unwrap(),
+        Operation::BitXor => builder.extern_functions.get("__bit_xor").unwrap(),
+        Operation::Shl => builder.extern_functions.get("__shl").unwrap(),
+        Operation::Shr => builder.extern_functions.get("__shr").unwrap(),
+        Operation::UShr => builder.extern_functions.get("__ushr").unwrap(),
+        Operation::InstanceOf => builder.extern_functions.get("__instanceof").unwrap(),
+        Operation::In => builder.extern_functions.get("__in").unwrap(),
     };
 
     let args = vec![left_ref, right_ref];
@@ -241,6 +334,152 @@ pub fn build_generic_op(
     }
 }
 
+pub unsafe fn build_unary(builder: &mut Builder, op: &UnaryOperation, value: Arc<Value>) -> LLVMValueRef {
+    let (name, args) = match op {
+        UnaryOperation::Void => {
+            let val_ref = build_value(builder, value);
+            let value_delete = builder.extern_functions.get("__value_delete").unwrap();
+            let args = vec![val_ref];
+            LLVMBuildCall2(
+                builder.builder,
+                value_delete.ft,
+                value_delete.func,
+                args.as_ptr() as *mut LLVMValueRef,
+                args.len() as u32,
+                b"__value_delete\0".as_ptr() as *const _,
+            );
+
+            ("__global_undefined", Vec::new())
+        }
+        UnaryOperation::Delete => match &*value {
+            Value::Identifier(n) if n.len() > 1 => {
+                let parts = n[..n.len() - 1].iter().map(|s| build_string(builder, s)).collect();
+                let access = build_array(builder, &parts);
+                let obj_ref = build_global_get(builder, access, true);
+                let name_ref = build_string(builder, &n[n.len() - 1]);
+
+                ("__delete_attr", vec![obj_ref, name_ref])
+            }
+            _ => return build_value(builder, Arc::new(Value::Boolean(true))),
+        },
+        _ => {
+            let name = match op {
+                UnaryOperation::Not => "__not",
+                UnaryOperation::BitNot => "__bit_not",
+                UnaryOperation::Negative => "__negative",
+                UnaryOperation::Positive => "__positive",
+                _ => "__typeof",
+            };
+
+            (name, vec![build_value(builder, value)])
+        }
+    };
+
+    let call = builder.extern_functions.get(name).unwrap();
+    LLVMBuildCall2(
+        builder.builder,
+        call.ft,
+        call.func,
+        args.as_ptr() as *mut LLVMValueRef,
+        args.len() as u32,
+        b"__unary_res\0".as_ptr() as *const _,
+    )
+}
+
+/// Resolves the object and the attribute name of a member or index target.
+unsafe fn build_attr_target(
+    builder: &mut Builder,
+    target: &Arc<Value>,
+) -> Option<(LLVMValueRef, LLVMValueRef)> {
+    let attr = match &**target {
+        Value::Identifier(path) => {
+            let (property, object) = path.split_last().unwrap();
+
+            let parts = object.iter().map(|s| build_string(builder, s)).collect();
+            let access = build_array(builder, &parts);
+            let obj_ref = build_global_get(builder, access, true);
+
+            (obj_ref, build_string(builder, property))
+        }
+        Value::Member {
+            object, property, ..
+        } => {
+            let obj_ref = build_value(builder, object.clone());
+
+            (obj_ref, build_string(builder, property))
+        }
+        Value::Index { object, index, .. } => {
+            let obj_ref = build_value(builder, object.clone());
+
+            (obj_ref, build_value(builder, index.clone()))
+        }
+        _ => {
+            log::warn!("can not assign to: {:?}", target);
+            return None;
+        }
+    };
+
+    Some(attr)
+}
+
+/// Writes an already built value into an assignment target.
+pub unsafe fn build_store(
+    builder: &mut Builder,
+    target: &Arc<Value>,
+    value_ref: LLVMValueRef,
+) -> LLVMValueRef {
+    if let Value::Identifier(path) = &**target {
+        if path.len() == 1 {
+            let name_ref = build_string(builder, &path[0]);
+            return build_global_set(builder, name_ref, value_ref, true);
+        }
+    }
+
+    match build_attr_target(builder, target) {
+        Some((obj_ref, name_ref)) => build_set_attr(builder, obj_ref, name_ref, value_ref),
+        None => 0 as _,
+    }
+}
+
+/// Writes `value` into a variable or, for member and index targets, into an attribute
+/// of an object through `__set_attr`.
+unsafe fn build_assign(
+    builder: &mut Builder,
+    target: &Arc<Value>,
+    op: &AssignOperation,
+    value: &Arc<Value>,
+) -> LLVMValueRef {
+    let (obj_ref, name_ref) = match &**target {
+        Value::Identifier(path) if path.len() == 1 => {
+            let name_ref = build_string(builder, &path[0]);
+            let value_ref = build_value(builder, value.clone());
+
+            return if *op == AssignOperation::Neutral {
+                build_global_set(builder, name_ref, value_ref, true)
+            } else {
+                let old_ref = build_global_get(builder, name_ref, false);
+                let new_ref = build_generic_op(builder, &op.into(), old_ref, value_ref);
+                build_global_set(builder, name_ref, new_ref, true)
+            };
+        }
+        _ => match build_attr_target(builder, target) {
+            Some(attr) => attr,
+            None => return 0 as _,
+        },
+    };
+
+    let value_ref = build_value(builder, value.clone());
+
+    let value_ref = if *op == AssignOperation::Neutral {
+        value_ref
+    } else {
+        let old_ref = build_get_attr_value(builder, obj_ref, name_ref);
+        build_generic_op(builder, &op.into(), old_ref, value_ref)
+    };
+
+    build_set_attr(builder, obj_ref, name_ref, value_ref)
+}
+
 pub unsafe fn build_value(builder: &mut Builder, value: Arc<Value>) -> LLVMValueRef {
     match &*value {
         Value::Number(n) => {
@@ -255,95 +494,109 @@ pub unsafe fn build_value(builder: &mut Builder, value: Arc<Value>) -> LLVMValue
                 b"__number_new\0".as_ptr() as *const _,
             )
         }
+        // the runtime has no arbitrary precision integers, BigInts become plain numbers
+        Value::BigInt(digits) => {
+            build_value(builder, Arc::new(Value::Number(digits.parse().unwrap())))
+        }
+        Value::Boolean(b) => {
+            let bool_new = builder.extern_functions.get("__boolean_new").unwrap();
+            let args = vec![LLVMConstInt(LLVMInt8TypeInContext(builder.context), *b as u64, 0)];
+            LLVMBuildCall2(
+                builder.builder,
+                bool_new.ft,
+                bool_new.func,
+                args.as_ptr() as *mut LLVMValueRef,
+                args.len() as u32,
+                b"__boolean_new\0".as_ptr() as *const _,
+            )
+        }
         Value::String(n) => build_string(builder, &n),
+        Value::Expression {
+            left,
+            op: op @ (Operation::And | Operation::Or),
+            right,
+        } => build_logical(builder, left, op, right),
         Value::Expression { left, op, right } => {
             let left_ref = build_value(builder, left.clone());
             let right_ref = build_value(builder, right.clone());
             // self.build_op(op, left_ref, right_ref)
             build_generic_op(builder, &op, left_ref, right_ref)
         }
+        Value::Unary { op, value } => build_unary(builder, op, value.clone()),
+        // async functions run to completion when called, so there is nothing to wait for
+        Value::Await(value) => build_value(builder, value.clone()),
+        // type assertions have no effect at runtime
+        Value::As { value, .. } | Value::Satisfies { value, .. } | Value::NonNull(value) => {
+            build_value(builder, value.clone())
+        }
+        Value::DynamicImport(_) => {
+            log::warn!("modules can not be loaded at runtime yet");
+            build_null(builder)
+        }
+        Value::Yield { value, delegate } => build_yield(builder, value, *delegate),
+        Value::Destructure { pattern, value } => build_destructure(builder, pattern, value),
         Value::Identifier(n) => {
+            if let Some(value) = const_enum_member(builder, n) {
+                return build_value(builder, value);
+            }
+
             let parts = n.iter().map(|s| build_string(builder, s)).collect();
             let access = build_array(builder, &parts);
             build_global_get(builder, access, true)
         }
-        Value::Array(a) => {
-            let mut values = Vec::new();
-
-            for p in a {
-                let p = build_value(builder, p.clone());
-                values.push(p);
+        Value::Array(a) => build_array_items(builder, a),
+        Value::Call { callee, args, optional } => build_call(builder, callee, args, *optional),
+        Value::Member { object, property, optional } => {
+            if let Value::Identifier(name) = &**object {
+                let path = [name.clone(), vec![property.clone()]].concat();
+                if let Some(value) = const_enum_member(builder, &path) {
+                    return build_value(builder, value);
+                }
             }
 
-            build_array(builder, &values)
-        }
-        Value::Call { identifier, args } => {
-            if identifier.len() == 1 {
-                if builder.extern_functions.contains_key(&identifier[0]) {
-                    let mut params: Vec<LLVMValueRef> = Vec::new();
-
-                    for p in args {
-                        params.push(build_value(builder, p.clone()));
-                    }
-
-                    let n = builder.extern_functions.get(&identifier[0]).unwrap();
-                    return LLVMBuildCall2(
-                        builder.builder,
-                        n.ft,
-                        n.func,
-                        params.as_ptr() as _,
-                        params.len() as _,
-                        b"__call_extern\0".as_ptr() as _,
-                    );
-                } else if builder.function_cache.contains_key(&identifier[0]) {
-                    let mut params: Vec<LLVMValueRef> = Vec::new();
-
-                    for p in args {
-                        params.push(build_value(builder, p.clone()));
-                    }
-
-                    let n = builder.function_cache.get(&identifier[0]).unwrap();
-                    return LLVMBuildCall2(
-                        builder.builder,
-                        n.ft,
-                        n.func,
-                        params.as_ptr() as _,
-                        params.len() as _,
-                        b"__call_intern\0".as_ptr() as _,
-                    );
-                }
+            let obj_ref = build_value(builder, object.clone());
+
+            if *optional {
+                build_optional_link(builder, obj_ref);
             }
 
-            0 as _
+            build_get_attr(builder, obj_ref, property)
         }
-        Value::Assign {
-            identifier,
-            op,
-            value,
-        } => {
-            let name_ref = build_string(builder, &*identifier);
-            let value_ref = build_value(builder, value.clone());
+        Value::Index { object, index, optional } => {
+            let obj_ref = build_value(builder, object.clone());
 
-            if *op == AssignOperation::Neutral {
-                build_global_set(builder, name_ref, value_ref, true)
-            } else {
-                let old_ref = build_global_get(builder, name_ref, false);
-                let new_ref = build_generic_op(builder, &op.into(), old_ref, value_ref);
-                build_global_set(builder, name_ref, new_ref, true)
+            if *optional {
+                build_optional_link(builder, obj_ref);
             }
+
+            let index_ref = build_value(builder, index.clone());
+            build_get_attr_value(builder, obj_ref, index_ref)
         }
-        Value::Undefined => {
-            let null = builder.extern_functions.get("__global_null").unwrap();
-            let args: Vec<LLVMValueRef> = Vec::new();
-            LLVMBuildCall2(
-                builder.builder,
-                null.ft,
-                null.func,
-                args.as_ptr() as *mut LLVMValueRef,
-                args.len() as u32,
-                b"__null\0".as_ptr() as *const _,
-            )
+        Value::Conditional { cond, then, els } => build_conditional(builder, cond, then, els),
+        Value::Nullish { left, right } => build_nullish(builder, left, right),
+        Value::Chain(chain) => build_chain(builder, chain),
+        Value::Object(properties) => build_object(builder, properties),
+        Value::Template { quasis, exprs } => build_template(builder, quasis, exprs),
+        Value::TaggedTemplate { tag, quasis, exprs } => {
+            let strings = quasis
+                .iter()
+                .map(|q| Arc::new(Value::String(q.clone())))
+                .collect();
+            let mut args = vec![Arc::new(Value::Array(strings))];
+            args.extend(exprs.iter().cloned());
+
+            build_call(builder, tag, &args, false)
+        }
+        Value::Assign { target, op, value } => build_assign(builder, target, op, value),
+        Value::Function(function) => {
+            let (func, _) = compile_function(builder, function);
+            build_function_value(builder, func)
         }
+        Value::This => build_this(builder),
+        Value::Super => build_super_class(builder),
+        Value::New { callee, args } => build_new(builder, callee, args),
+        Value::Null => build_null(builder),
+        Value::Undefined => build_undefined(builder),
         _ => {
             log::warn!("could not handle: {:?}", value);
             0 as _
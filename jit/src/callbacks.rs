@@ -1,4 +1,7 @@
-use std::{sync::Arc, ffi::CStr};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 use crate::stdlib::Array;
 
@@ -8,31 +11,37 @@ pub unsafe extern "C" fn global_null() -> *const Value {
     Arc::into_raw(Arc::new(Value::Null))
 }
 
-pub unsafe extern "C" fn global_get(ctx: *mut Context, name: *const Value) -> *const Value {
+pub unsafe extern "C" fn global_undefined() -> *const Value {
+    Arc::into_raw(Arc::new(Value::Undefined))
+}
+
+/// Resolves a variable and walks down the attributes of an access path like `a.b.c`.
+unsafe fn lookup(ctx: &Context, path: &[Arc<Value>]) -> Option<Arc<Value>> {
+    let mut parts = path.iter();
+
+    let mut val = match &**parts.next()? {
+        Value::Str(s) => ctx.get(s)?,
+        _ => return None,
+    };
+
+    for part in parts {
+        val = get_property(&val, part.clone());
+    }
+
+    Some(val)
+}
+
+pub unsafe extern "C" fn global_get(ctx: *const Context, name: *const Value) -> *const Value {
     #[cfg(feature = "trace")]
     log::trace!("!! get {:?} !!", *name);
 
     let val = match *name {
-        Value::Str(ref s) => {
-            (*ctx).variables.get(s)
-        }
-        Value::Array(ref a) => {
-            if let Value::Str(ref s) = *a[0] {
-                (*ctx).variables.get(s)
-            }
-            else {
-                None
-            }
-        }
+        Value::Str(ref s) => (*ctx).get(s),
+        Value::Array(ref a) => lookup(&*ctx, a),
         Value::Class(ref clss) => {
             let mut a = clss.lock().unwrap();
             if let Some(arr) = a.as_any().downcast_ref::<Array>() {
-                if let Value::Str(ref s) = *arr.data[0] {
-                    (*ctx).variables.get(s)
-                }
-                else {
-                    None
-                }
+                lookup(&*ctx, &arr.data)
             }
             else {
                 None
@@ -42,19 +51,72 @@ pub unsafe extern "C" fn global_get(ctx: *mut Context, name: *const Value) -> *c
     };
 
     if let Some(val) = val {
-        return Arc::into_raw(val.clone());
+        return Arc::into_raw(val);
     }
 
     0 as _
 }
 
+/// Calls `func` with `obj` as `this`, the same way compiled code does.
+unsafe fn call_function(obj: &Arc<Value>, func: &Arc<Value>, args: Vec<Arc<Value>>) -> Option<Arc<Value>> {
+    let addr = get_func_addr(Arc::as_ptr(func));
+
+    if addr == 0 {
+        return None;
+    }
+
+    let args = Arc::new(Value::Class(Array::with_data(args)));
+    let scope = get_func_scope(Arc::as_ptr(func));
+    let func: unsafe extern "C" fn(*const Value, *const Value, *const Context) -> *const Value =
+        std::mem::transmute(addr);
+    let ret = func(Arc::as_ptr(obj), Arc::as_ptr(&args), scope);
+
+    if ret.is_null() {
+        None
+    } else {
+        Some(Arc::from_raw(ret))
+    }
+}
+
+/// Calls a method of `obj` without arguments.
+pub unsafe fn call_method(obj: &Arc<Value>, name: &str) -> Option<Arc<Value>> {
+    let func = obj.get(Arc::new(Value::Str(name.to_owned())));
+    call_function(obj, &func, Vec::new())
+}
+
+/// The getter (`kind` is `get`) or setter (`set`) of an object for the property `name`.
+fn accessor(obj: &Value, kind: &str, name: &Value) -> Option<Arc<Value>> {
+    match obj {
+        Value::Object(o) => o
+            .lock()
+            .unwrap()
+            .get(&format!("@{}:{}", kind, name.property_key()?))
+            .cloned(),
+        _ => None,
+    }
+}
+
+/// Reads an attribute of `obj`, a getter is called instead if there is one.
+pub unsafe fn get_property(obj: &Arc<Value>, name: Arc<Value>) -> Arc<Value> {
+    match accessor(obj, "get", &name) {
+        Some(getter) => {
+            call_function(obj, &getter, Vec::new()).unwrap_or_else(|| Arc::new(Value::Undefined))
+        }
+        None => obj.get(name),
+    }
+}
+
 pub unsafe extern "C" fn get_func_addr(val: *const Value) -> u64 {
+    if val.is_null() {
+        return 0;
+    }
+
     #[cfg(feature = "trace")]
     log::trace!("!! get func {:?} !!", *val);
 
     match &*val {
-        Value::Function(f) => {
-            return *f;
+        Value::Function { func, .. } => {
+            return *func;
         }
         Value::Method { class: _, func } => {
             return *func;
@@ -65,67 +127,251 @@ pub unsafe extern "C" fn get_func_addr(val: *const Value) -> u64 {
     0
 }
 
+/// The name of the variable a `__global_get` or `__global_set` refers to.
+unsafe fn variable_name(name: &Value) -> Option<String> {
+    match name {
+        Value::Str(s) => Some(s.clone()),
+        Value::Array(a) => match &*a[0] {
+            Value::Str(s) => Some(s.clone()),
+            _ => None,
+        },
+        Value::Class(clss) => {
+            let mut a = clss.lock().unwrap();
+            match a.as_any().downcast_ref::<Array>()?.data[0].as_ref() {
+                Value::Str(s) => Some(s.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 pub unsafe extern "C" fn global_set(
-    ctx: *mut Context,
+    ctx: *const Context,
     name: *const Value,
     val: *mut Value,
 ) -> *const Value {
     #[cfg(feature = "trace")]
     log::trace!("!! set {:?} = {:?} !!", *name, *val);
 
-    match *name {
-        Value::Str(ref s) => {
-            (*ctx).variables.insert(
-                s.clone(),
-                Arc::from_raw(val),
-            );
-        }
-        Value::Array(ref a) => {
-            if let Value::Str(ref s) = *a[0] {
-                (*ctx).variables.insert(
-                    s.clone(),
-                    Arc::from_raw(val),
-                );
-            }
-        }
-        Value::Class(ref clss) => {
-            let mut a = clss.lock().unwrap();
-            if let Some(arr) = a.as_any().downcast_ref::<Array>() {
-                if let Value::Str(ref s) = *arr.data[0] {
-                    (*ctx).variables.insert(
-                        s.clone(),
-                        Arc::from_raw(val),
-                    );
-                }
-            }
-        }
-        _ => {}
+    if let Some(name) = variable_name(&*name) {
+        (*ctx).assign(&name, Arc::from_raw(val));
+    }
+
+    0 as _
+}
+
+/// Declares a variable in the scope `ctx`, like `let`, `const` or a parameter does.
+pub unsafe extern "C" fn global_define(
+    ctx: *const Context,
+    name: *const Value,
+    val: *mut Value,
+) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! define {:?} = {:?} !!", *name, *val);
+
+    if let Some(name) = variable_name(&*name) {
+        (*ctx).define(name, Arc::from_raw(val));
     }
 
     0 as _
 }
 
+/// Creates the scope of a function call. `parent` is only borrowed.
+pub unsafe extern "C" fn scope_new(parent: *const Context) -> *const Context {
+    #[cfg(feature = "trace")]
+    log::trace!("!! new scope !!");
+
+    let scope = if parent.is_null() {
+        Context::new()
+    } else {
+        Arc::increment_strong_count(parent);
+        Context::with_parent(Arc::from_raw(parent))
+    };
+
+    Arc::into_raw(scope)
+}
+
+pub unsafe extern "C" fn scope_delete(scope: *const Context) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! delete scope !!");
+
+    Arc::from_raw(scope);
+
+    0 as _
+}
+
 pub unsafe extern "C" fn get_attr(obj: *const Value, name: *const Value) -> *const Value {
+    if obj.is_null() {
+        return global_undefined();
+    }
+
     #[cfg(feature = "trace")]
     log::trace!("!! get-attr {:?} . {:?} !!", *obj, *name);
 
     let objv = Arc::from_raw(obj);
     let namev = Arc::from_raw(name);
 
-    let ret = objv.get(namev.clone());
+    let ret = get_property(&objv, namev.clone());
 
     let _ = Arc::into_raw(objv);
     let _ = Arc::into_raw(namev);
     Arc::into_raw(ret)
 }
 
+pub unsafe extern "C" fn set_attr(obj: *const Value, name: *const Value, val: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! set-attr {:?} . {:?} = {:?} !!", *obj, *name, *val);
+
+    let namev = Arc::from_raw(name);
+    let valv = Arc::from_raw(val);
+
+    if !obj.is_null() {
+        let objv = Arc::from_raw(obj);
+
+        match accessor(&objv, "set", &namev) {
+            Some(setter) => {
+                call_function(&objv, &setter, vec![valv]);
+            }
+            None => objv.set(namev, valv),
+        }
+
+        let _ = Arc::into_raw(objv);
+    }
+
+    0 as _
+}
+
+pub extern "C" fn object_new() -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! new object !!");
+    Arc::into_raw(Arc::new(Value::Object(Mutex::new(HashMap::new()))))
+}
+
+pub unsafe extern "C" fn object_spread(obj: *const Value, src: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! spread {:?} into {:?} !!", *src, *obj);
+
+    if src.is_null() {
+        return 0 as _;
+    }
+
+    let srcv = Arc::from_raw(src);
+
+    // the entries are copied first, an object may be spread into itself
+    let entries: Vec<(Arc<Value>, Arc<Value>)> = match &*srcv {
+        Value::Object(o) => o
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(key, val)| (Arc::new(Value::Str(key.clone())), val.clone()))
+            .collect(),
+        Value::Class(c) => {
+            let mut clss = c.lock().unwrap();
+            match clss.as_any().downcast_ref::<Array>() {
+                Some(arr) => arr
+                    .data
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, val)| (Arc::new(Value::Number(idx as f64)), val.clone()))
+                    .collect(),
+                None => Vec::new(),
+            }
+        }
+        _ => Vec::new(),
+    };
+
+    for (key, val) in entries {
+        (*obj).set(key, val);
+    }
+
+    0 as _
+}
+
+/// Collects the elements of an array from `start` on, for `[a, ...rest]` patterns.
+pub unsafe extern "C" fn array_rest(arr: *const Value, start: *const Value) -> *const Value {
+    let start = Arc::from_raw(start).to_number() as usize;
+    let rest = Array::new();
+
+    if let Value::Class(c) = &*arr {
+        let mut clss = c.lock().unwrap();
+        if let Some(arr) = clss.as_any().downcast_ref::<Array>() {
+            let mut rest = rest.lock().unwrap();
+            if let Some(rest) = rest.as_any().downcast_mut::<Array>() {
+                rest.data.extend(arr.data.iter().skip(start).cloned());
+            }
+        }
+    }
+
+    Arc::into_raw(Arc::new(Value::Class(rest)))
+}
+
+/// Copies all properties of an object except `keys`, for `{ a, ...rest }` patterns.
+pub unsafe extern "C" fn object_rest(obj: *const Value, keys: *const Value) -> *const Value {
+    let keys = Arc::from_raw(keys);
+    let mut rest = HashMap::new();
+
+    if let Value::Object(o) = &*obj {
+        rest = o.lock().unwrap().clone();
+
+        if let Value::Class(c) = &*keys {
+            let mut clss = c.lock().unwrap();
+            if let Some(keys) = clss.as_any().downcast_ref::<Array>() {
+                for key in keys.data.iter().filter_map(|k| k.property_key()) {
+                    rest.remove(&key);
+                }
+            }
+        }
+    }
+
+    Arc::into_raw(Arc::new(Value::Object(Mutex::new(rest))))
+}
+
+/// A function value, which keeps the scope it is defined in alive. `scope` is only borrowed.
+pub unsafe extern "C" fn function_new(addr: u64, scope: *const Context) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! new function {} !!", addr);
+
+    Arc::increment_strong_count(scope);
+    Arc::into_raw(Arc::new(Value::Function {
+        func: addr,
+        scope: Arc::from_raw(scope),
+    }))
+}
+
+/// The scope a function was defined in, native methods have none.
+pub unsafe extern "C" fn get_func_scope(val: *const Value) -> *const Context {
+    if val.is_null() {
+        return 0 as _;
+    }
+
+    match &*val {
+        Value::Function { scope, .. } => Arc::as_ptr(scope),
+        _ => 0 as _,
+    }
+}
+
 pub unsafe extern "C" fn to_bool(val: *const Value) -> i8 {
+    // unknown variables are handed over as null pointers
+    if val.is_null() {
+        return 0;
+    }
+
     let val = Arc::from_raw(val);
     let bool = val.to_bool();
     let _ = Arc::into_raw(val);
     bool as _
 }
 
+pub unsafe extern "C" fn is_nullish(val: *const Value) -> i8 {
+    // unknown variables are handed over as null pointers
+    if val.is_null() {
+        return 1;
+    }
+
+    (*val).is_nullish() as _
+}
+
 pub unsafe extern "C" fn add(left: *const Value, right: *const Value) -> *const Value {
     #[cfg(feature = "trace")]
     log::trace!("!! add {:?} {:?} !!", *left, *right);
@@ -139,6 +385,16 @@ pub unsafe extern "C" fn add(left: *const Value, right: *const Value) -> *const
         }
     }
 
+    if let Value::Str(_) | Value::Class(_) | Value::Array(_) | Value::Object(_) = *left_rc {
+        let concat = left_rc.to_js_string() + &right_rc.to_js_string();
+        return Arc::into_raw(Arc::new(Value::Str(concat)));
+    }
+
+    if let Value::Str(_) | Value::Class(_) | Value::Array(_) | Value::Object(_) = *right_rc {
+        let concat = left_rc.to_js_string() + &right_rc.to_js_string();
+        return Arc::into_raw(Arc::new(Value::Str(concat)));
+    }
+
     Arc::into_raw(Arc::new(Value::Number(0.0)))
 }
 
@@ -206,6 +462,89 @@ pub unsafe extern "C" fn _mod(left: *const Value, right: *const Value) -> *const
     Arc::into_raw(Arc::new(Value::Number(0.0)))
 }
 
+pub unsafe extern "C" fn pow(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! pow !!");
+
+    let left_rc = Arc::from_raw(left);
+    let right_rc = Arc::from_raw(right);
+
+    if let Value::Number(l) = *left_rc {
+        if let Value::Number(r) = *right_rc {
+            return Arc::into_raw(Arc::new(Value::Number(l.powf(r))));
+        }
+    }
+
+    Arc::into_raw(Arc::new(Value::Number(0.0)))
+}
+
+/// Applies a bitwise operation on the ToInt32 conversion of both operands.
+unsafe fn bitwise(left: *const Value, right: *const Value, op: fn(i32, i32) -> i32) -> *const Value {
+    let left_rc = Arc::from_raw(left);
+    let right_rc = Arc::from_raw(right);
+
+    if let Value::Number(l) = *left_rc {
+        if let Value::Number(r) = *right_rc {
+            let res = op(l as i64 as i32, r as i64 as i32);
+            return Arc::into_raw(Arc::new(Value::Number(res as f64)));
+        }
+    }
+
+    Arc::into_raw(Arc::new(Value::Number(0.0)))
+}
+
+pub unsafe extern "C" fn bit_and(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! bit and !!");
+
+    bitwise(left, right, |l, r| l & r)
+}
+
+pub unsafe extern "C" fn bit_or(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! bit or !!");
+
+    bitwise(left, right, |l, r| l | r)
+}
+
+pub unsafe extern "C" fn bit_xor(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! bit xor !!");
+
+    bitwise(left, right, |l, r| l ^ r)
+}
+
+pub unsafe extern "C" fn shl(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! shl !!");
+
+    bitwise(left, right, |l, r| l.wrapping_shl(r as u32 & 0x1f))
+}
+
+pub unsafe extern "C" fn shr(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! shr !!");
+
+    bitwise(left, right, |l, r| l.wrapping_shr(r as u32 & 0x1f))
+}
+
+pub unsafe extern "C" fn ushr(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! ushr !!");
+
+    let left_rc = Arc::from_raw(left);
+    let right_rc = Arc::from_raw(right);
+
+    if let Value::Number(l) = *left_rc {
+        if let Value::Number(r) = *right_rc {
+            let res = (l as i64 as u32).wrapping_shr(r as i64 as u32 & 0x1f);
+            return Arc::into_raw(Arc::new(Value::Number(res as f64)));
+        }
+    }
+
+    Arc::into_raw(Arc::new(Value::Number(0.0)))
+}
+
 pub unsafe extern "C" fn gt(left: *const Value, right: *const Value) -> *const Value {
     #[cfg(feature = "trace")]
     log::trace!("!! gt !!");
@@ -277,7 +616,7 @@ pub unsafe extern "C" fn eq(left: *const Value, right: *const Value) -> *const V
     let left_rc = Arc::from_raw(left);
     let right_rc = Arc::from_raw(right);
 
-    Arc::into_raw(Arc::new(Value::Boolean(*left_rc == *right_rc)))
+    Arc::into_raw(Arc::new(Value::Boolean(left_rc.loose_eq(&right_rc))))
 }
 
 pub unsafe extern "C" fn neq(left: *const Value, right: *const Value) -> *const Value {
@@ -287,9 +626,76 @@ pub unsafe extern "C" fn neq(left: *const Value, right: *const Value) -> *const
     let left_rc = Arc::from_raw(left);
     let right_rc = Arc::from_raw(right);
 
+    Arc::into_raw(Arc::new(Value::Boolean(!left_rc.loose_eq(&right_rc))))
+}
+
+pub unsafe extern "C" fn strict_eq(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! strict eq !!");
+
+    let left_rc = Arc::from_raw(left);
+    let right_rc = Arc::from_raw(right);
+
+    Arc::into_raw(Arc::new(Value::Boolean(*left_rc == *right_rc)))
+}
+
+pub unsafe extern "C" fn strict_neq(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! strict neq !!");
+
+    let left_rc = Arc::from_raw(left);
+    let right_rc = Arc::from_raw(right);
+
     Arc::into_raw(Arc::new(Value::Boolean(*left_rc != *right_rc)))
 }
 
+pub unsafe extern "C" fn instance_of(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! instanceof !!");
+
+    let left_rc = Arc::from_raw(left);
+    let right_rc = Arc::from_raw(right);
+
+    // instances know their class, classes know the class they extend
+    let class_key = Arc::new(Value::Str("@class".to_owned()));
+    let super_key = Arc::new(Value::Str("@super".to_owned()));
+    let mut found = false;
+
+    if let Value::Object(_) = &*left_rc {
+        let mut class = left_rc.get(class_key);
+
+        while let Value::Object(_) = &*class {
+            if Arc::ptr_eq(&class, &right_rc) {
+                found = true;
+                break;
+            }
+
+            class = class.get(super_key.clone());
+        }
+    }
+
+    Arc::into_raw(Arc::new(Value::Boolean(found)))
+}
+
+pub unsafe extern "C" fn _in(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! in !!");
+
+    let left_rc = Arc::from_raw(left);
+    let right_rc = Arc::from_raw(right);
+
+    let found = match &*right_rc {
+        Value::Object(obj) => match &*left_rc {
+            Value::Str(name) => obj.lock().unwrap().contains_key(name),
+            _ => false,
+        },
+        Value::Class(_) => *right_rc.get(left_rc.clone()) != Value::Undefined,
+        _ => false,
+    };
+
+    Arc::into_raw(Arc::new(Value::Boolean(found)))
+}
+
 pub unsafe extern "C" fn and(left: *const Value, right: *const Value) -> *const Value {
     #[cfg(feature = "trace")]
     log::trace!("!! and !!");
@@ -311,6 +717,112 @@ pub unsafe extern "C" fn or(left: *const Value, right: *const Value) -> *const V
 }
 
 
+/// Takes over the operand of a unary operator. Unknown variables are handed over as null
+/// pointers and count as `undefined`.
+unsafe fn unary_operand(val: *const Value) -> Arc<Value> {
+    if val.is_null() {
+        Arc::from_raw(global_undefined())
+    } else {
+        Arc::from_raw(val)
+    }
+}
+
+pub unsafe extern "C" fn not(val: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! not !!");
+
+    let val_rc = unary_operand(val);
+
+    Arc::into_raw(Arc::new(Value::Boolean(!val_rc.to_bool())))
+}
+
+pub unsafe extern "C" fn bit_not(val: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! bit not !!");
+
+    let val_rc = unary_operand(val);
+    let res = !(val_rc.to_number() as i64 as i32);
+
+    Arc::into_raw(Arc::new(Value::Number(res as f64)))
+}
+
+pub unsafe extern "C" fn negative(val: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! negative !!");
+
+    let val_rc = unary_operand(val);
+
+    Arc::into_raw(Arc::new(Value::Number(-val_rc.to_number())))
+}
+
+pub unsafe extern "C" fn positive(val: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! positive !!");
+
+    let val_rc = unary_operand(val);
+
+    Arc::into_raw(Arc::new(Value::Number(val_rc.to_number())))
+}
+
+pub unsafe extern "C" fn type_of(val: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! typeof !!");
+
+    // unknown variables are handed over as null pointers
+    if val.is_null() {
+        return Arc::into_raw(Arc::new(Value::Str("undefined".to_owned())));
+    }
+
+    let val_rc = Arc::from_raw(val);
+
+    Arc::into_raw(Arc::new(Value::Str(val_rc.type_of().to_owned())))
+}
+
+pub unsafe extern "C" fn delete_attr(obj: *const Value, name: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! delete-attr {:?} . {:?} !!", *obj, *name);
+
+    let namev = Arc::from_raw(name);
+
+    if !obj.is_null() {
+        if let (Value::Object(o), Value::Str(name)) = (&*obj, &*namev) {
+            let _old = o.lock().unwrap().remove(name);
+        }
+
+        Arc::from_raw(obj);
+    }
+
+    Arc::into_raw(Arc::new(Value::Boolean(true)))
+}
+
+pub unsafe extern "C" fn to_string(val: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! to string !!");
+
+    if val.is_null() {
+        return Arc::into_raw(Arc::new(Value::Str("undefined".to_owned())));
+    }
+
+    let val_rc = Arc::from_raw(val);
+
+    if let Value::Str(_) = *val_rc {
+        return Arc::into_raw(val_rc);
+    }
+
+    Arc::into_raw(Arc::new(Value::Str(val_rc.to_js_string())))
+}
+
+pub unsafe extern "C" fn string_concat(left: *const Value, right: *const Value) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! string concat !!");
+
+    let left_rc = Arc::from_raw(left);
+    let right_rc = Arc::from_raw(right);
+
+    let concat = left_rc.to_js_string() + &right_rc.to_js_string();
+    Arc::into_raw(Arc::new(Value::Str(concat)))
+}
+
 pub extern "C" fn string_new() -> *const Value {
     #[cfg(feature = "trace")]
     log::trace!("!! new string !!");
@@ -325,12 +837,11 @@ pub extern "C" fn string_copy(v: *const Value) -> *const Value {
     }
 }
 
-pub unsafe extern "C" fn string_from(bytes: *mut i8) -> *const Value {
+pub unsafe extern "C" fn string_from(bytes: *const u8, len: u64) -> *const Value {
     #[cfg(feature = "trace")]
     log::trace!("!! string from !!");
-    // let data = CString::from_raw(bytes);
-    let data = CStr::from_ptr(bytes);
-    let owned = data.to_str().unwrap().to_string();
+    let data = std::slice::from_raw_parts(bytes, len as usize);
+    let owned = String::from_utf8_lossy(data).into_owned();
     Arc::into_raw(Arc::new(Value::Str(owned)))
 }
 
@@ -340,6 +851,21 @@ pub extern "C" fn number_new(v: f64) -> *const Value {
     Arc::into_raw(Arc::new(Value::Number(v)))
 }
 
+pub extern "C" fn boolean_new(v: i8) -> *const Value {
+    #[cfg(feature = "trace")]
+    log::trace!("!! new boolean {} !!", v);
+    Arc::into_raw(Arc::new(Value::Boolean(v != 0)))
+}
+
+/// Hands out another reference to a value the caller only borrows.
+pub unsafe extern "C" fn value_ref(a: *const Value) -> *const Value {
+    if !a.is_null() {
+        Arc::increment_strong_count(a);
+    }
+
+    a
+}
+
 pub unsafe extern "C" fn value_delete(a: *const Value) -> *const Value {
     #[cfg(feature = "trace")]
     log::trace!("!! delete value {:?} !!", *a);
@@ -1,17 +1,71 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, RwLock},
+};
 
 use super::value::Value;
 
+/// A scope with variables. The module namespace is the outermost scope, every function
+/// call gets its own scope, whose parent is the scope the function was defined in.
 pub struct Context {
     pub parent: Option<Arc<Context>>,
-    pub variables: HashMap<String, Arc<Value>>,
+    variables: RwLock<HashMap<String, Arc<Value>>>,
 }
 
 impl Context {
     pub fn new() -> Arc<Context> {
         Arc::new(Self {
             parent: None,
-            variables: HashMap::new(),
+            variables: RwLock::new(HashMap::new()),
         })
     }
-}
\ No newline at end of file
+
+    pub fn with_parent(parent: Arc<Context>) -> Arc<Context> {
+        Arc::new(Self {
+            parent: Some(parent),
+            variables: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Looks a variable up in this scope and then in all parent scopes.
+    pub fn get(&self, name: &str) -> Option<Arc<Value>> {
+        if let Some(val) = self.variables.read().unwrap().get(name) {
+            return Some(val.clone());
+        }
+
+        self.parent.as_ref()?.get(name)
+    }
+
+    /// Declares a variable in this scope, it hides variables of the parent scopes.
+    pub fn define(&self, name: String, val: Arc<Value>) {
+        // the replaced value is dropped after the lock is released
+        let _old = self.variables.write().unwrap().insert(name, val);
+    }
+
+    /// Assigns to the nearest scope which has the variable. Unknown variables end up in
+    /// the outermost scope.
+    pub fn assign(&self, name: &str, val: Arc<Value>) {
+        let mut scope = self;
+
+        loop {
+            let mut variables = scope.variables.write().unwrap();
+
+            if variables.contains_key(name) || scope.parent.is_none() {
+                let _old = variables.insert(name.to_owned(), val);
+                drop(variables);
+                return;
+            }
+
+            drop(variables);
+            scope = scope.parent.as_ref().unwrap();
+        }
+    }
+}
+
+impl fmt::Debug for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // functions are stored in the scope they capture, so the variables are left out
+        f.debug_struct("Context").finish_non_exhaustive()
+    }
+}
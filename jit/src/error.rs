@@ -4,12 +4,19 @@ use std::{error::Error, fmt};
 #[derive(Debug)]
 pub enum JitError {
     ModuleVerify(String),
+    Coroutine(String),
+    /// The program can't be built, like a `continue` outside of a loop.
+    Compile(String),
 }
 
 impl Error for JitError {}
 
 impl fmt::Display for JitError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::ModuleVerify(message) => write!(f, "invalid module: {}", message),
+            Self::Coroutine(message) => write!(f, "generators can't be lowered: {}", message),
+            Self::Compile(message) => write!(f, "{}", message),
+        }
     }
 }
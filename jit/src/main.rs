@@ -49,8 +49,8 @@ fn main() {
 
     match runtime.load_file(args.filename, args.ir) {
         Ok(n) => {
-            log::info!("an -> {:?}", n.namespace.variables.get("an"));
-            log::info!("bu -> {:?}", n.namespace.variables.get("bu"));
+            log::info!("an -> {:?}", n.namespace.get("an"));
+            log::info!("bu -> {:?}", n.namespace.get("bu"));
         }
         Err(e) => {
             log::error!("{}", e);
@@ -1,14 +1,16 @@
-use std::{sync::Arc, time::SystemTime};
+use std::{collections::HashMap, sync::Arc, time::SystemTime};
 
 use llvm_sys::execution_engine::{LLVMDisposeExecutionEngine, LLVMExecutionEngineRef};
 
-use super::context::Context;
+use super::{context::Context, value::Value};
 
 pub struct Module {
     id: Vec<u8>,
     pub(crate) init_fn: Option<extern "C" fn()>,
     pub(crate) ee: LLVMExecutionEngineRef,
     pub namespace: Arc<Context>,
+    /// The exported names and the path of the variable they refer to.
+    pub(crate) exports: HashMap<String, Vec<String>>,
 }
 
 impl Module {
@@ -18,6 +20,7 @@ impl Module {
             init_fn: None,
             ee: 0 as _,
             namespace: Context::new(),
+            exports: HashMap::new(),
         }
     }
 
@@ -25,6 +28,18 @@ impl Module {
         self.id.clone()
     }
 
+    /// The value of an export, once the module ran.
+    pub fn export(&self, name: &str) -> Option<Arc<Value>> {
+        let (first, path) = self.exports.get(name)?.split_first()?;
+        let mut value = self.namespace.get(first)?;
+
+        for part in path {
+            value = value.get(Arc::new(Value::Str(part.clone())));
+        }
+
+        Some(value)
+    }
+
     pub fn run(&self) {
         let start = SystemTime::now();
         if let Some(func) = self.init_fn {
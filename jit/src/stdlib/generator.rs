@@ -0,0 +1,182 @@
+use std::{
+    alloc::{alloc, dealloc, Layout},
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, Mutex, Weak},
+};
+
+use crate::{callbacks::call_method, value::Class, Value};
+
+/// A running generator function.
+///
+/// The generator body is compiled into a LLVM coroutine. `handle` points to its frame,
+/// which starts with the addresses of the resume and the destroy function.
+#[derive(Debug)]
+pub struct Generator {
+    handle: usize,
+    value: Arc<Value>,
+    delegate: Option<Arc<Value>>,
+    done: bool,
+    me: Weak<Mutex<Self>>,
+}
+
+type FrameFn = unsafe extern "C" fn(*mut u8);
+type MethodFn = unsafe extern "C" fn(*const Value, *const Value) -> *const Value;
+
+fn step(value: Arc<Value>, done: bool) -> *const Value {
+    let mut obj = HashMap::new();
+    obj.insert("value".to_owned(), value);
+    obj.insert("done".to_owned(), Arc::new(Value::Boolean(done)));
+    Arc::into_raw(Arc::new(Value::Object(Mutex::new(obj))))
+}
+
+unsafe fn with_generator<F, R>(gen: *const Value, f: F) -> Option<R>
+where
+    F: FnOnce(&mut Generator) -> R,
+{
+    if let Value::Class(clss) = &*gen {
+        let mut clss = clss.lock().unwrap();
+        if let Some(gen) = clss.as_any().downcast_mut::<Generator>() {
+            return Some(f(gen));
+        }
+    }
+
+    None
+}
+
+unsafe extern "C" fn generator_next(gen: *const Value, _args: *const Value) -> *const Value {
+    let handle = match with_generator(gen, |g| (!g.done).then_some(g.handle)) {
+        Some(Some(handle)) => handle,
+        _ => return step(Arc::new(Value::Undefined), true),
+    };
+
+    // the generator body accesses the generator itself, so it must not be locked here
+    let resume: FrameFn = std::mem::transmute(*(handle as *const usize));
+    resume(handle as _);
+
+    let (value, done) = with_generator(gen, |g| {
+        let value = std::mem::replace(&mut g.value, Arc::new(Value::Undefined));
+        (value, g.done)
+    })
+    .unwrap();
+
+    if done {
+        let destroy: FrameFn = std::mem::transmute(*(handle as *const usize).add(1));
+        destroy(handle as _);
+    }
+
+    step(value, done)
+}
+
+unsafe extern "C" fn generator_iterator(gen: *const Value, _args: *const Value) -> *const Value {
+    crate::callbacks::value_ref(gen)
+}
+
+impl Generator {
+    pub fn new(handle: usize) -> Arc<Mutex<dyn Class>> {
+        Arc::new_cyclic(|me| {
+            Mutex::new(Generator {
+                handle,
+                value: Arc::new(Value::Undefined),
+                delegate: None,
+                done: false,
+                me: me.clone(),
+            })
+        })
+    }
+}
+
+/// Allocates a coroutine frame. The size is stored in front of the frame.
+pub(crate) unsafe extern "C" fn generator_alloc(size: u64) -> *mut u8 {
+    let layout = Layout::from_size_align(size as usize + 16, 16).unwrap();
+    let mem = alloc(layout);
+    *(mem as *mut u64) = size;
+
+    mem.add(16)
+}
+
+pub(crate) unsafe extern "C" fn generator_free(mem: *mut u8) -> *const Value {
+    if !mem.is_null() {
+        let mem = mem.sub(16);
+        let size = *(mem as *const u64);
+        dealloc(mem, Layout::from_size_align(size as usize + 16, 16).unwrap());
+    }
+
+    0 as _
+}
+
+pub(crate) extern "C" fn generator_new(handle: *mut u8) -> *const Value {
+    Arc::into_raw(Arc::new(Value::Class(Generator::new(handle as _))))
+}
+
+pub(crate) unsafe extern "C" fn generator_yield(gen: *const Value, val: *const Value) -> *const Value {
+    let val = Arc::from_raw(val);
+    with_generator(gen, |g| g.value = val);
+
+    0 as _
+}
+
+pub(crate) unsafe extern "C" fn generator_return(gen: *const Value, val: *const Value) -> *const Value {
+    let val = Arc::from_raw(val);
+    with_generator(gen, |g| {
+        g.value = val;
+        g.done = true;
+    });
+
+    0 as _
+}
+
+pub(crate) unsafe extern "C" fn generator_delegate(gen: *const Value, val: *const Value) -> *const Value {
+    let val = Arc::from_raw(val);
+    let iter = call_method(&val, "@iterator");
+    with_generator(gen, |g| g.delegate = iter);
+
+    0 as _
+}
+
+pub(crate) unsafe extern "C" fn generator_delegate_next(gen: *const Value) -> *const Value {
+    // the delegate might be a generator as well, so don't keep the lock while stepping
+    let iter = with_generator(gen, |g| g.delegate.clone()).flatten();
+
+    match iter.and_then(|iter| call_method(&iter, "next")) {
+        Some(step) => Arc::into_raw(step),
+        None => step(Arc::new(Value::Undefined), true),
+    }
+}
+
+impl Drop for Generator {
+    fn drop(&mut self) {
+        // a generator which never finished still owns its frame
+        if !self.done {
+            unsafe {
+                let destroy: FrameFn = std::mem::transmute(*(self.handle as *const usize).add(1));
+                destroy(self.handle as _);
+            }
+        }
+    }
+}
+
+impl Class for Generator {
+    fn set(&mut self, _name: Arc<Value>, _val: Arc<Value>) {}
+
+    fn get(&self, name: Arc<Value>) -> Arc<Value> {
+        if let Value::Str(name) = &*name {
+            let func = match name.as_str() {
+                "next" => generator_next as MethodFn as usize as u64,
+                "@iterator" => generator_iterator as MethodFn as usize as u64,
+                _ => return Arc::new(Value::Undefined),
+            };
+
+            return Arc::new(Value::Method {
+                class: self.me.upgrade().unwrap(),
+                func,
+            });
+        }
+
+        Arc::new(Value::Undefined)
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
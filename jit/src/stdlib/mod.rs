@@ -4,7 +4,9 @@ use std::{
     sync::{Arc, Mutex, Weak},
 };
 
-use crate::{builder::Builder, value::Class, Value};
+use crate::{builder::Builder, callbacks::call_method, value::Class, Value};
+
+pub(crate) mod generator;
 
 #[derive(Debug)]
 pub struct Array {
@@ -12,7 +14,7 @@ pub struct Array {
     me: Weak<Mutex<Self>>,
 }
 
-unsafe extern "C" fn array_iterator(arr: *const Value) -> *const Value {
+unsafe extern "C" fn array_iterator(arr: *const Value, _args: *const Value) -> *const Value {
     let val = match &*arr {
         // Value::Array(_arr) => Value::Class(ArrayIterator::new()),
         Value::Class(arr) => Value::Class(ArrayIterator::new(arr.clone())),
@@ -24,9 +26,13 @@ unsafe extern "C" fn array_iterator(arr: *const Value) -> *const Value {
 
 impl Array {
     pub fn new() -> Arc<Mutex<dyn Class>> {
+        Self::with_data(Vec::new())
+    }
+
+    pub fn with_data(data: Vec<Arc<Value>>) -> Arc<Mutex<dyn Class>> {
         Arc::new_cyclic(|me| {
             Mutex::new(Array {
-                data: Vec::new(),
+                data,
                 me: me.clone(),
             })
         })
@@ -61,9 +67,48 @@ impl Array {
             &Value::Null as *const _
         }
 
+        /// Pushes all values of an array or another iterable, for `...value`.
+        unsafe extern "C" fn array_extend(arr: *mut Value, v: *const Value) -> *const Value {
+            let src = Arc::from_raw(v);
+            let mut values = None;
+
+            if let Value::Class(c) = &*src {
+                let mut clss = c.lock().unwrap();
+                if let Some(src) = clss.as_any().downcast_ref::<Array>() {
+                    values = Some(src.data.clone());
+                }
+            }
+
+            let values = values.unwrap_or_else(|| {
+                let mut values = Vec::new();
+
+                if let Some(iter) = call_method(&src, "@iterator") {
+                    while let Some(step) = call_method(&iter, "next") {
+                        let done = step.get(Arc::new(Value::Str("done".into())));
+                        if done.to_bool() {
+                            break;
+                        }
+                        values.push(step.get(Arc::new(Value::Str("value".into()))));
+                    }
+                }
+
+                values
+            });
+
+            if let Value::Class(a) = &*arr {
+                let mut ag = a.lock().unwrap();
+                if let Some(arr) = ag.as_any().downcast_mut::<Array>() {
+                    arr.data.extend(values);
+                }
+            }
+
+            0 as _
+        }
+
         module.add_fn("__array_new", array_new as _, 0);
         module.add_fn("__array_push", array_push as _, 2);
-        module.add_fn("__array_iterator", array_iterator as _, 1);
+        module.add_fn("__array_extend", array_extend as _, 2);
+        module.add_fn("__array_iterator", array_iterator as _, 2);
 
         ArrayIterator::register(module);
     }
@@ -78,9 +123,11 @@ impl Class for Array {
             Value::Number(n) => {
                 let idx = *n as usize;
 
-                if idx < self.data.len() as _ {
-                    self.data[idx] = val;
+                if idx >= self.data.len() {
+                    self.data.resize(idx + 1, Arc::new(Value::Undefined));
                 }
+
+                self.data[idx] = val;
             }
             _ => {}
         }
@@ -97,7 +144,7 @@ impl Class for Array {
                 if idx < self.data.len() as _ {
                     self.data[idx].clone()
                 } else {
-                    Arc::new(Value::Null)
+                    Arc::new(Value::Undefined)
                 }
             }
             Value::Str(name) => match name.as_str() {
@@ -106,9 +153,9 @@ impl Class for Array {
                     class: self.me.upgrade().unwrap(),
                     func: array_iterator as _,
                 }),
-                _ => Arc::new(Value::Null),
+                _ => Arc::new(Value::Undefined),
             },
-            _ => Arc::new(Value::Null),
+            _ => Arc::new(Value::Undefined),
         }
     }
 
@@ -124,7 +171,7 @@ pub struct ArrayIterator {
     me: Weak<Mutex<Self>>,
 }
 
-unsafe extern "C" fn array_iterator_next(arr_it: *const Value) -> *const Value {
+unsafe extern "C" fn array_iterator_next(arr_it: *const Value, _args: *const Value) -> *const Value {
     if let Value::Class(arr_it) = &*arr_it {
         let mut arr_it = arr_it.lock().unwrap();
         if let Some(arr_it) = arr_it.as_any().downcast_mut::<ArrayIterator>() {
@@ -133,9 +180,9 @@ unsafe extern "C" fn array_iterator_next(arr_it: *const Value) -> *const Value {
     }
 
     let mut obj = HashMap::new();
-    obj.insert("value".to_owned(), Arc::new(Value::Null));
+    obj.insert("value".to_owned(), Arc::new(Value::Undefined));
     obj.insert("done".to_owned(), Arc::new(Value::Boolean(true)));
-    Arc::into_raw(Arc::new(Value::Object(obj)))
+    Arc::into_raw(Arc::new(Value::Object(Mutex::new(obj))))
 }
 
 impl ArrayIterator {
@@ -150,12 +197,12 @@ impl ArrayIterator {
     }
 
     pub fn register(module: &mut Builder) {
-        module.add_fn("__array_iterator_next", array_iterator_next as _, 1);
+        module.add_fn("__array_iterator_next", array_iterator_next as _, 2);
     }
 
     pub fn next(&mut self) -> Arc<Value> {
         let mut obj = HashMap::new();
-        obj.insert("value".to_owned(), Arc::new(Value::Null));
+        obj.insert("value".to_owned(), Arc::new(Value::Undefined));
         obj.insert("done".to_owned(), Arc::new(Value::Boolean(true)));
 
         let mut arr = self.array.lock().unwrap();
@@ -168,7 +215,7 @@ impl ArrayIterator {
             }
         }
         
-        Arc::new(Value::Object(obj))
+        Arc::new(Value::Object(Mutex::new(obj)))
     }
 }
 
@@ -189,7 +236,7 @@ impl Class for ArrayIterator {
             _ => {}
         }
 
-        Arc::new(Value::Null)
+        Arc::new(Value::Undefined)
     }
 
     fn as_any(&mut self) -> &mut dyn Any {
@@ -4,6 +4,8 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use crate::{context::Context, stdlib::Array};
+
 pub trait Class: std::fmt::Debug {
     fn set(&mut self, name: Arc<Value>, val: Arc<Value>);
 
@@ -18,33 +20,115 @@ pub enum Value {
     Boolean(bool),
     Str(String),
     Array(Vec<Arc<Value>>),
-    Object(HashMap<String, Arc<Value>>),
-    Function(u64),
+    /// The attributes are shared by everyone holding the object, so they are locked.
+    Object(Mutex<HashMap<String, Arc<Value>>>),
+    /// A compiled function and the scope it was defined in.
+    Function {
+        func: u64,
+        scope: Arc<Context>,
+    },
     Method {
         class: Arc<Mutex<dyn Class>>,
         func: u64,
     },
     Class(Arc<Mutex<dyn Class>>),
     Null,
+    Undefined,
 }
 
 impl Value {
     pub fn to_bool(&self) -> bool {
         match self {
-            Self::Number(n) => *n != 0.0,
+            Self::Number(n) => *n != 0.0 && !n.is_nan(),
             Self::Boolean(b) => *b,
-            Self::Null => false,
+            Self::Str(s) => !s.is_empty(),
+            Self::Null | Self::Undefined => false,
             _ => true,
         }
     }
 
-    pub fn set(&mut self, _name: Arc<Value>, _val: Arc<Value>) {}
+    pub fn to_number(&self) -> f64 {
+        match self {
+            Self::Number(n) => *n,
+            Self::Boolean(b) => *b as u8 as f64,
+            Self::Str(s) => {
+                let s = s.trim();
+
+                if s.is_empty() {
+                    0.0
+                } else {
+                    s.parse().unwrap_or(f64::NAN)
+                }
+            }
+            Self::Null => 0.0,
+            Self::Undefined => f64::NAN,
+            _ => f64::NAN,
+        }
+    }
+
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "number",
+            Self::Boolean(_) => "boolean",
+            Self::Str(_) => "string",
+            Self::Function { .. } | Self::Method { .. } => "function",
+            Self::Undefined => "undefined",
+            _ => "object",
+        }
+    }
+
+    /// Converts a value into the name of an object property.
+    pub fn property_key(&self) -> Option<String> {
+        match self {
+            Self::Str(_) | Self::Number(_) | Self::Boolean(_) | Self::Null | Self::Undefined => {
+                Some(self.to_js_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// The `ToString` conversion of javascript.
+    pub fn to_js_string(&self) -> String {
+        match self {
+            Self::Number(n) => number_to_string(*n),
+            Self::Boolean(b) => b.to_string(),
+            Self::Str(s) => s.clone(),
+            Self::Array(a) => join(a),
+            Self::Object(_) => "[object Object]".to_owned(),
+            Self::Function { .. } | Self::Method { .. } => "function () { [native code] }".to_owned(),
+            Self::Class(c) => {
+                let mut clss = c.lock().unwrap();
+                match clss.as_any().downcast_ref::<Array>() {
+                    Some(arr) => join(&arr.data),
+                    None => "[object Object]".to_owned(),
+                }
+            }
+            Self::Null => "null".to_owned(),
+            Self::Undefined => "undefined".to_owned(),
+        }
+    }
+
+    pub fn set(&self, name: Arc<Value>, val: Arc<Value>) {
+        match self {
+            Self::Object(a) => {
+                if let Some(name) = name.property_key() {
+                    // the replaced value is dropped after the lock is released
+                    let _old = a.lock().unwrap().insert(name, val);
+                }
+            }
+            Self::Class(c) => {
+                let mut clss = c.lock().unwrap();
+                clss.set(name, val);
+            }
+            _ => {}
+        }
+    }
 
     pub fn get(&self, name: Arc<Value>) -> Arc<Value> {
         match self {
             Self::Object(a) => {
-                if let Value::Str(name) = &*name {
-                    if let Some(val) = a.get(name) {
+                if let Some(name) = name.property_key() {
+                    if let Some(val) = a.lock().unwrap().get(&name) {
                         return val.clone();
                     }
                 }
@@ -56,10 +140,49 @@ impl Value {
             _ => {}
         }
 
-        Arc::new(Value::Null)
+        Arc::new(Value::Undefined)
+    }
+
+    pub fn is_nullish(&self) -> bool {
+        matches!(self, Self::Null | Self::Undefined)
+    }
+
+    /// The `==` comparison of javascript, which only differs from `===` in treating
+    /// `null` and `undefined` as equal.
+    pub fn loose_eq(&self, other: &Self) -> bool {
+        (self.is_nullish() && other.is_nullish()) || self == other
     }
 }
 
+fn number_to_string(n: f64) -> String {
+    if n.is_nan() {
+        "NaN".to_owned()
+    } else if n.is_infinite() {
+        if n > 0.0 { "Infinity" } else { "-Infinity" }.to_owned()
+    } else if n == 0.0 {
+        "0".to_owned()
+    } else if n.abs() >= 1e21 || n.abs() < 1e-6 {
+        let exp = format!("{:e}", n);
+        match exp.split_once('e') {
+            Some((mantissa, power)) if !power.starts_with('-') => format!("{}e+{}", mantissa, power),
+            _ => exp,
+        }
+    } else {
+        format!("{}", n)
+    }
+}
+
+fn join(values: &[Arc<Value>]) -> String {
+    values
+        .iter()
+        .map(|v| match &**v {
+            Value::Null | Value::Undefined => String::new(),
+            v => v.to_js_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 impl PartialEq for Value {
     fn eq(&self, other: &Self) -> bool {
         match self {
@@ -83,14 +206,16 @@ impl PartialEq for Value {
                     return *a == *b;
                 }
             }
-            Self::Object(a) => {
-                if let Self::Object(b) = other {
-                    return *a == *b;
+            // objects are only equal to themselves
+            Self::Object(_) => {
+                if let Self::Object(_) = other {
+                    return std::ptr::eq(self, other);
                 }
             }
-            Self::Function(a) => {
-                if let Self::Function(b) = other {
-                    return *a == *b;
+            Self::Function { func, scope } => {
+                let (a, a_scope) = (func, scope);
+                if let Self::Function { func, scope } = other {
+                    return *a == *func && Arc::ptr_eq(a_scope, scope);
                 }
             }
             Self::Method { class: _, func } => {
@@ -109,6 +234,11 @@ impl PartialEq for Value {
                     return true;
                 }
             }
+            Self::Undefined => {
+                if let Self::Undefined = other {
+                    return true;
+                }
+            }
         }
 
         false
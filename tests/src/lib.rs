@@ -8,8 +8,8 @@ use std::{
 use typescript_jit::{Module, Value};
 
 pub fn check(module: Arc<Module>, name: &str, goal: f64) -> Result<(), String> {
-    match module.namespace.variables.get(name) {
-        Some(var) => match &**var {
+    match module.namespace.get(name) {
+        Some(var) => match &*var {
             Value::Number(n) => {
                 if *n == goal {
                     Ok(())
@@ -26,6 +26,44 @@ pub fn check(module: Arc<Module>, name: &str, goal: f64) -> Result<(), String> {
     }
 }
 
+pub fn check_string(module: Arc<Module>, name: &str, goal: &str) -> Result<(), String> {
+    match module.namespace.get(name) {
+        Some(var) => match &*var {
+            Value::Str(s) => {
+                if s == goal {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "string in '{}' not as expected {:?} != {:?}",
+                        name, s, goal
+                    ))
+                }
+            }
+            _ => Err(format!("expected string in '{}' but got: {:?}", name, var)),
+        },
+        None => Err(format!("expected variable '{}'", name)),
+    }
+}
+
+pub fn check_boolean(module: Arc<Module>, name: &str, goal: bool) -> Result<(), String> {
+    match module.namespace.get(name) {
+        Some(var) => match &*var {
+            Value::Boolean(b) => {
+                if *b == goal {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "boolean in '{}' not as expected {} != {}",
+                        name, b, goal
+                    ))
+                }
+            }
+            _ => Err(format!("expected boolean in '{}' but got: {:?}", name, var)),
+        },
+        None => Err(format!("expected variable '{}'", name)),
+    }
+}
+
 pub struct TestLogger {
     file: Mutex<File>,
 }
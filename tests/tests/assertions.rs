@@ -0,0 +1,57 @@
+use typescript_ast::ast::{statement::Statement, tstype::TsType, value::Value};
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+fn const_value<'a>(statements: &'a [Statement], name: &str) -> &'a Value {
+    statements
+        .iter()
+        .find_map(|s| match s {
+            Statement::Const(declarators) if declarators[0].pattern.names() == [name] => {
+                declarators[0].value.as_deref()
+            }
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn run_assertions() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/assertions.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/assertions.ts",
+        Some("results/assertions.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "asNumber", 21.0)?;
+    check(module.clone(), "angled", 40.0)?;
+    check(module.clone(), "settingsSize", 3.0)?;
+    check(module.clone(), "nonNull", 4.0)?;
+    check(module.clone(), "asserted", 9.0)?;
+    check(module.clone(), "method", 10.0)?;
+    check(module.clone(), "afterAssign", 100.0)?;
+    check(module.clone(), "chained", 101.0)?;
+    check(module.clone(), "doubleCast", 20.0)?;
+
+    let ast = typescript_ast::parser::file("tests/assertions.ts").map_err(|e| e.to_string())?;
+    let statements = &ast.statements;
+
+    assert!(matches!(
+        const_value(statements, "angled"),
+        Value::Expression { left, .. } if matches!(&**left, Value::As { kind: TsType::Number, .. })
+    ));
+    assert!(matches!(const_value(statements, "settings"), Value::Satisfies { .. }));
+    assert!(matches!(
+        const_value(statements, "nonNull"),
+        Value::Call { callee, .. } if matches!(&**callee, Value::Member { object, .. } if matches!(**object, Value::NonNull(_)))
+    ));
+    assert!(matches!(
+        const_value(statements, "doubleCast"),
+        Value::As { value, .. } if matches!(&**value, Value::As { kind: TsType::Unknown, .. })
+    ));
+
+    Ok(())
+}
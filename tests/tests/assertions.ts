@@ -0,0 +1,25 @@
+interface Settings {
+    size: number
+}
+
+class Counter {
+    count: number = 0;
+    add(step: number) {
+        this.count += step;
+        return this.count;
+    }
+}
+
+const input: unknown = 20;
+const asNumber = (input as number) + 1;
+const angled = <number>input * 2;
+const settings = { size: 3 } satisfies Settings;
+const settingsSize = settings.size;
+const maybe: Counter | null = new Counter();
+const nonNull = maybe!.add(4);
+const asserted = (maybe as Counter).add(5);
+const method = maybe!.add!(1);
+maybe!.count = 100;
+const afterAssign = maybe!.count;
+const chained = maybe?.count! + 1;
+const doubleCast = input as unknown as number;
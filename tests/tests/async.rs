@@ -0,0 +1,38 @@
+use typescript_ast::ast::statement::Statement;
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check, check_string};
+
+#[test]
+fn run_async() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/async.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/async.ts",
+        Some("results/async.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "result", 42.0)?;
+    check(module.clone(), "awaited", 22.0)?;
+    check(module.clone(), "asyncParam", 6.0)?;
+    check_string(module.clone(), "asyncExpression", "done")?;
+    check(module.clone(), "loaded", 5.0)?;
+    check(module.clone(), "size", 3.0)?;
+
+    let ast = typescript_ast::parser::file("tests/async.ts").map_err(|e| e.to_string())?;
+
+    for stmnt in &ast.statements {
+        match stmnt {
+            Statement::Function(f) => assert!(f.is_async),
+            Statement::Class(c) => {
+                assert!(c.methods[0].modifiers.is_static && !c.methods[0].function.is_async);
+                assert!(!c.methods[1].modifiers.is_static && c.methods[1].function.is_async);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+async function fetchValue() {
+    return 21
+}
+
+async function doubled() {
+    const value = await fetchValue()
+    return value * 2
+}
+
+const result = doubled()
+const awaited = (async () => await fetchValue() + 1)()
+const asyncParam = (async x => x * 3)(2)
+const asyncExpression = (async function () {
+    return "done"
+})()
+
+const service = {
+    async load() {
+        return 5
+    },
+}
+const loaded = service.load()
+
+class Store {
+    static create() {
+        return new Store()
+    }
+
+    async size() {
+        return 3
+    }
+}
+
+const size = Store.create().size()
@@ -0,0 +1,63 @@
+use typescript_ast::ast::{
+    class::{Access, MethodKind},
+    statement::Statement,
+};
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check, check_string};
+
+#[test]
+fn run_class_syntax() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/class_syntax.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/class_syntax.ts",
+        Some("results/class_syntax.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "area", 9.0)?;
+    check_string(module.clone(), "described", "4 sides")?;
+    check(module.clone(), "squareSize", 3.0)?;
+    check_string(module.clone(), "name", "anna's square")?;
+    check_string(module.clone(), "label", "renamed")?;
+    check_string(module.clone(), "created", "factory's square")?;
+    check(module.clone(), "count", 2.0)?;
+    check(module.clone(), "instances", 10.0)?;
+    check(module.clone(), "inheritedCount", 2.0)?;
+    check_string(module.clone(), "inheritedCreated", "factory's square")?;
+
+    let ast = typescript_ast::parser::file("tests/class_syntax.ts").map_err(|e| e.to_string())?;
+
+    match &ast.statements[0] {
+        Statement::Class(c) => {
+            assert!(c.is_abstract);
+            assert_eq!(c.attributes[0].modifiers.access, Access::Protected);
+            assert!(c.attributes[0].modifiers.is_readonly);
+            assert!(c.methods[0].modifiers.is_abstract);
+            assert!(c.constructor.is_some());
+        }
+        s => return Err(format!("expected a class, got {:?}", s)),
+    }
+
+    match &ast.statements[1] {
+        Statement::Class(c) => {
+            assert!(c.attributes[0].is_optional);
+            assert!(c.attributes[1].modifiers.is_static);
+            assert_eq!(c.static_blocks.len(), 1);
+
+            let constructor = c.constructor.as_ref().unwrap();
+            assert_eq!(constructor.properties.len(), 2);
+            assert_eq!(constructor.properties[1].modifiers.access, Access::Private);
+
+            assert!(c.methods[0].modifiers.is_override);
+            assert_eq!(c.methods[1].kind, MethodKind::Get);
+            assert_eq!(c.methods[2].kind, MethodKind::Set);
+            assert!(c.methods[3].modifiers.is_static);
+        }
+        s => return Err(format!("expected a class, got {:?}", s)),
+    }
+
+    Ok(())
+}
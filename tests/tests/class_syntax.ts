@@ -0,0 +1,59 @@
+abstract class Shape {
+    protected readonly sides: number
+    abstract area(): number
+
+    constructor(sides: number) {
+        this.sides = sides
+    }
+
+    describe() {
+        return this.sides + " sides"
+    }
+}
+
+class Square extends Shape {
+    private label?: string
+    static count = 0
+    static instances = 0
+
+    static {
+        Square.instances = 10
+    }
+
+    constructor(public size: number, private readonly owner: string = "nobody") {
+        super(4)
+        Square.count += 1
+    }
+
+    override area(): number {
+        return this.size * this.size
+    }
+
+    get name() {
+        return this.owner + "'s square"
+    }
+
+    set name(value: string) {
+        this.label = value
+    }
+
+    static create(size: number) {
+        return new Square(size, "factory")
+    }
+}
+
+const square = new Square(3, "anna")
+const area = square.area()
+const described = square.describe()
+const squareSize = square.size
+const name = square.name
+square.name = "renamed"
+const label = square.label
+const created = Square.create(2).name
+const count = Square.count
+const instances = Square.instances
+
+class Cube extends Square {}
+
+const inheritedCount = Cube.count
+const inheritedCreated = Cube.create(1).name
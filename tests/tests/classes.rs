@@ -0,0 +1,30 @@
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check, check_boolean, check_string};
+
+#[test]
+fn run_classes() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/classes.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/classes.ts",
+        Some("results/classes.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "initial", 10.0)?;
+    check(module.clone(), "incremented", 15.0)?;
+    check(module.clone(), "chained", 12.0)?;
+    check_string(module.clone(), "birdDescription", "tweety has 2 legs")?;
+    check_string(module.clone(), "birdSound", "tweet after ...")?;
+    check(module.clone(), "wings", 2.0)?;
+    check_string(module.clone(), "dogDescription", "rex has 4 legs")?;
+    check_boolean(module.clone(), "birdIsBird", true)?;
+    check_boolean(module.clone(), "birdIsAnimal", true)?;
+    check_boolean(module.clone(), "birdIsDog", false)?;
+    check_boolean(module.clone(), "counterIsAnimal", false)?;
+    check_boolean(module.clone(), "plainIsAnimal", false)?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+class Counter {
+    count: number = 10
+    step: number
+
+    constructor(step: number) {
+        this.step = step
+    }
+
+    increment() {
+        this.count += this.step
+        return this
+    }
+
+    current() {
+        return this.count
+    }
+}
+
+const counter = new Counter(5)
+const initial = counter.count
+counter.increment()
+const incremented = counter.current()
+const chained = new Counter(1).increment().increment().current()
+
+class Animal {
+    legs: number = 4
+    name: string
+
+    constructor(name: string) {
+        this.name = name
+    }
+
+    describe() {
+        return this.name + " has " + this.legs + " legs"
+    }
+
+    sound() {
+        return "..."
+    }
+}
+
+class Bird extends Animal {
+    wings: number = 2
+
+    constructor(name: string) {
+        super(name)
+        this.legs = 2
+    }
+
+    sound() {
+        return "tweet after " + super.sound()
+    }
+}
+
+class Dog extends Animal {}
+
+const bird = new Bird("tweety")
+const birdDescription = bird.describe()
+const birdSound = bird.sound()
+const wings = bird.wings
+const dog = new Dog("rex")
+const dogDescription = dog.describe()
+
+function plain() {
+    return this
+}
+
+const unbound = plain()
+const outside = this
+
+const birdIsBird = bird instanceof Bird
+const birdIsAnimal = bird instanceof Animal
+const birdIsDog = bird instanceof Dog
+const counterIsAnimal = counter instanceof Animal
+const plainIsAnimal = { legs: 4 } instanceof Animal
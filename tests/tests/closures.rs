@@ -0,0 +1,32 @@
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check, check_string};
+
+#[test]
+fn run_closures() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/closures.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/closures.ts",
+        Some("results/closures.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check_string(module.clone(), "name", "name")?;
+    check(module.clone(), "doubled", 42.0)?;
+    check(module.clone(), "squared", 16.0)?;
+    check(module.clone(), "added", 3.0)?;
+    check(module.clone(), "wrapped", 7.0)?;
+    check(module.clone(), "decremented", 2.0)?;
+    check(module.clone(), "tripled", 9.0)?;
+    check(module.clone(), "iife", 99.0)?;
+    check(module.clone(), "arrowIife", 2.0)?;
+    check(module.clone(), "applied", 15.0)?;
+    check_string(module.clone(), "kind", "function")?;
+    check(module.clone(), "counted", 3.0)?;
+    check(module.clone(), "otherCounted", 2.0)?;
+    check(module.clone(), "shadowed", 2.0)?;
+
+    Ok(())
+}
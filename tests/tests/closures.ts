@@ -0,0 +1,59 @@
+const dave = (): string => {
+    return "name"
+}
+const name = dave()
+
+const double = (n: number) => n * 2
+const doubled = double(21)
+
+const square = x => x * x
+const squared = square(4)
+
+const add = (a: number, b: number): number => a + b
+const added = add(1, 2)
+
+const makeObject = () => ({ value: 7 })
+const wrapped = makeObject().value
+
+const anonymous = function (n: number) {
+    return n - 1
+}
+const decremented = anonymous(3)
+
+const named = function triple(n: number) {
+    return n * 3
+}
+const tripled = named(3)
+
+const iife = (function () {
+    return 99
+})()
+const arrowIife = ((n: number) => n + 1)(1)
+
+const apply = (f, v) => f(v)
+const applied = apply(x => x + 10, 5)
+
+const kind = typeof square
+
+function counter() {
+    let count = 0
+    return () => {
+        count = count + 1
+        return count
+    }
+}
+const first = counter()
+const second = counter()
+first()
+first()
+second()
+const counted = first()
+const otherCounted = second()
+
+function one() {
+    return 1
+}
+function callParam(one) {
+    return one()
+}
+const shadowed = callParam(() => 2)
@@ -0,0 +1,53 @@
+use typescript_ast::ast::statement::Statement;
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+#[test]
+fn run_comments() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/comments.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/comments.ts",
+        Some("results/comments.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "sum", 3.0)?;
+    check(module.clone(), "called", 3.0)?;
+    check(module.clone(), "z", 3.0)?;
+    check(module.clone(), "matched", 2.0)?;
+
+    let ast = typescript_ast::parser::file("tests/comments.ts").map_err(|e| e.to_string())?;
+
+    for stmnt in &ast.statements {
+        match stmnt {
+            Statement::Function(f) if f.name.as_deref() == Some("add") => {
+                assert_eq!(f.doc.as_deref(), Some("Adds two numbers.\n@param a first summand"));
+                assert_eq!(f.params[0].doc.as_deref(), Some("left side"));
+                assert_eq!(f.params[1].doc, None);
+            }
+            Statement::Class(c) => {
+                assert_eq!(c.doc.as_deref(), Some("A documented class."));
+                assert_eq!(c.attributes[0].doc.as_deref(), Some("horizontal position"));
+                assert_eq!(c.methods[0].function.doc.as_deref(), Some("Moves the point."));
+            }
+            Statement::Interface(i) => {
+                assert_eq!(i.doc.as_deref(), Some("A documented interface."));
+                assert_eq!(i.attributes[0].doc.as_deref(), Some("area of the shape"));
+            }
+            Statement::Function(f) => {
+                assert_eq!(f.params[0].doc.as_deref(), Some("any number"));
+            }
+            Statement::Enum(e) => {
+                assert_eq!(e.doc.as_deref(), Some("Levels of detail."));
+                assert_eq!(e.members[0].doc.as_deref(), Some("the lowest level"));
+                assert_eq!(e.members[1].doc, None);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
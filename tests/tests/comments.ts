@@ -0,0 +1,56 @@
+/* a block comment */
+/**/
+/*
+ * spanning multiple lines
+ */
+
+/**
+ * Adds two numbers.
+ * @param a first summand
+ */
+function add(/** left side */ a: number, b: number): number {
+    /** stray documentation */
+    return 1 /* inline */ + 2 // trailing
+}
+
+/** A documented class. */
+class Point {
+    /** horizontal position */
+    x: number
+
+    /** Moves the point. */
+    move() {
+        return 0
+    }
+}
+
+/** A documented interface. */
+interface Shape {
+    /** area of the shape */
+    area: number
+}
+
+/** not attached to anything */
+const sum = add(1, 2)
+
+/** Levels of detail. */
+enum Level {
+    /** the lowest level */
+    Low,
+    High,
+}
+
+function twice(/** any number */ value) {
+    return value * 2
+}
+
+const point = { /** documented key */ a: 1, /** shorthand */ sum }
+const called = add(/** first */ 1, 2)
+let z = 1 /** c */ + 2
+
+let matched = 0
+switch (sum) {
+    /** before a case */
+    case 3:
+        matched = /** inline */ twice(point.a)
+}
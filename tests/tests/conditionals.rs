@@ -0,0 +1,30 @@
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+#[test]
+fn run_conditionals() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/conditionals.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/conditionals.ts",
+        Some("results/conditionals.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "bigger", 2.0)?;
+    check(module.clone(), "nested", 2.0)?;
+    check(module.clone(), "lazy", 4.0)?;
+    check(module.clone(), "calls", 0.0)?;
+    check(module.clone(), "fallback", 5.0)?;
+    check(module.clone(), "zero", 0.0)?;
+    check(module.clone(), "chained", 7.0)?;
+    check(module.clone(), "length", 3.0)?;
+    check(module.clone(), "second", 2.0)?;
+    check(module.clone(), "missing", 8.0)?;
+    check(module.clone(), "deep", 9.0)?;
+    check(module.clone(), "noCall", 10.0)?;
+
+    Ok(())
+}
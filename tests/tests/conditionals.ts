@@ -0,0 +1,20 @@
+
+const a = 1
+const b = 2
+const nothing = null
+const arr = [1, 2, 3]
+let calls = 0
+
+const bigger = a > b ? a : b
+const nested = a > b ? 1 : a < b ? 2 : 3
+const lazy = true ? 4 : (calls += 1)
+
+const fallback = nothing ?? 5
+const zero = 0 ?? 6
+const chained = nothing ?? nothing ?? 7
+
+const length = arr?.length
+const second = arr?.[1]
+const missing = nothing?.length ?? 8
+const deep = nothing?.first.second ?? 9
+const noCall = nothing?.() ?? 10
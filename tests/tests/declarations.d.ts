@@ -0,0 +1,14 @@
+/** Writes to the host log. */
+declare function log(message: string): void
+
+function now(): number
+
+interface Options {
+    verbose: boolean
+}
+
+type Settings = Options
+
+declare namespace Host {
+    function exit(code: number): void
+}
@@ -0,0 +1,77 @@
+use typescript_ast::ast::{declaration::Declaration, statement::Statement};
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check_string};
+
+#[test]
+fn run_declarations() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/declarations.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/declarations.ts",
+        Some("results/declarations.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check_string(module.clone(), "padded", "[5]")?;
+    check_string(module.clone(), "formatted", "> done")?;
+
+    let ast = typescript_ast::parser::file("tests/declarations.ts").map_err(|e| e.to_string())?;
+
+    match &ast.statements[0] {
+        Statement::Declare(Declaration::Function(f)) => assert!(f.is_declaration),
+        s => return Err(format!("expected a declared function, got {:?}", s)),
+    }
+
+    match &ast.statements[1] {
+        Statement::Declare(Declaration::Variable { name, is_const, .. }) => {
+            assert_eq!(name, "VERSION");
+            assert!(is_const);
+        }
+        s => return Err(format!("expected a declared variable, got {:?}", s)),
+    }
+
+    match &ast.statements[3] {
+        Statement::Declare(Declaration::Global(statements)) => assert_eq!(statements.len(), 1),
+        s => return Err(format!("expected a global declaration, got {:?}", s)),
+    }
+
+    match &ast.statements[4] {
+        Statement::Declare(Declaration::Module { name, statements }) => {
+            assert_eq!(name, "host");
+            assert_eq!(statements.len(), 2);
+        }
+        s => return Err(format!("expected a module declaration, got {:?}", s)),
+    }
+
+    match &ast.statements[5] {
+        Statement::Declare(Declaration::Class(c)) => {
+            assert_eq!(c.doc.as_deref(), Some("A class the host provides."));
+            assert!(c.methods[0].function.is_declaration);
+        }
+        s => return Err(format!("expected a declared class, got {:?}", s)),
+    }
+
+    match &ast.statements[6] {
+        Statement::Function(f) => assert!(f.is_declaration),
+        s => return Err(format!("expected an overload, got {:?}", s)),
+    }
+
+    let dts = typescript_ast::parser::file("tests/declarations.d.ts").map_err(|e| e.to_string())?;
+    assert_eq!(dts.statements.len(), 5);
+
+    match &dts.statements[0] {
+        Statement::Declare(Declaration::Function(f)) => {
+            assert_eq!(f.doc.as_deref(), Some("Writes to the host log."));
+        }
+        s => return Err(format!("expected a declared function, got {:?}", s)),
+    }
+
+    match &dts.statements[1] {
+        Statement::Declare(Declaration::Function(f)) => assert!(f.is_declaration),
+        s => return Err(format!("expected a declared function, got {:?}", s)),
+    }
+
+    Ok(())
+}
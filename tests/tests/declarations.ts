@@ -0,0 +1,42 @@
+declare function print(one: any): void
+declare const VERSION: string
+declare let counter: number
+
+declare global {
+    interface Window {
+        title: string
+    }
+}
+
+declare module "host" {
+    function log(message: string): void
+    const ready: boolean
+}
+
+/** A class the host provides. */
+declare class Host {
+    name: string
+    constructor(name: string)
+    start(): void
+}
+
+function pad(value: string): string
+function pad(value: number): string
+function pad(value: any) {
+    return "[" + value + "]"
+}
+
+class Formatter {
+    constructor(prefix: string)
+    constructor(prefix: any) {
+        this.prefix = prefix
+    }
+
+    format(value: string): string
+    format(value: any) {
+        return this.prefix + value
+    }
+}
+
+const padded = pad(5)
+const formatted = new Formatter("> ").format("done")
@@ -0,0 +1,47 @@
+use typescript_ast::ast::{pattern::Pattern, statement::Statement};
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check, check_string};
+
+#[test]
+fn run_destructuring() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/destructuring.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/destructuring.ts",
+        Some("results/destructuring.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "x", 1.0)?;
+    check(module.clone(), "why", 2.0)?;
+    check(module.clone(), "w", 4.0)?;
+    check(module.clone(), "restSum", 3.0)?;
+    check(module.clone(), "first", 10.0)?;
+    check(module.clone(), "third", 7.0)?;
+    check(module.clone(), "tailLength", 2.0)?;
+    check_string(module.clone(), "deep", "found")?;
+    check(module.clone(), "summed", 10.0)?;
+    check(module.clone(), "defaulted", 5.0)?;
+    check(module.clone(), "left", 2.0)?;
+    check(module.clone(), "right", 1.0)?;
+    check(module.clone(), "assigned", 9.0)?;
+    check(module.clone(), "pairs", 3.0)?;
+
+    let ast = typescript_ast::parser::file("tests/destructuring.ts").map_err(|e| e.to_string())?;
+
+    match &ast.statements[1] {
+        Statement::Const(declarators) => {
+            let Pattern::Object { properties, rest } = &declarators[0].pattern else {
+                return Err(format!("expected an object pattern, got {:?}", declarators[0].pattern));
+            };
+            assert_eq!(properties.len(), 3);
+            assert!(properties[2].default.is_some());
+            assert_eq!(rest.as_ref().and_then(|r| r.name()), Some("others"));
+        }
+        s => return Err(format!("expected an object pattern, got {:?}", s)),
+    }
+
+    Ok(())
+}
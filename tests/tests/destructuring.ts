@@ -0,0 +1,33 @@
+const point = { x: 1, y: 2, z: 3 }
+const { x, y: why, w = 4, ...others } = point
+const restSum = others.z
+
+const [first, , third = 7, ...tail] = [10, 20, null, 40, 50]
+const tailLength = tail.length
+
+const { inner: { deep } } = { inner: { deep: "found" } }
+
+function sum([a, b], { scale = 1 }) {
+    return (a + b) * scale
+}
+
+const summed = sum([2, 3], { scale: 2 })
+
+function withDefault(value = 5) {
+    return value
+}
+
+const defaulted = withDefault()
+
+let left = 1
+let right = 2;
+[left, right] = [right, left]
+
+const target = { value: 0 };
+({ value: target.value } = { value: 9 })
+const assigned = target.value
+
+let pairs = 0
+for (const [key, count] of [["a", 1], ["b", 2]]) {
+    pairs += count
+}
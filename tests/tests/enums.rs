@@ -0,0 +1,45 @@
+use typescript_ast::ast::statement::Statement;
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check, check_string};
+
+#[test]
+fn run_enums() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/enums.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/enums.ts",
+        Some("results/enums.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "up", 0.0)?;
+    check(module.clone(), "right", 11.0)?;
+    check_string(module.clone(), "leftName", "Left")?;
+    check_string(module.clone(), "downName", "Down")?;
+    check_string(module.clone(), "green", "g")?;
+    check_string(module.clone(), "lightBlue", "lb")?;
+    check(module.clone(), "both", 3.0)?;
+    check_string(module.clone(), "negativeName", "Negative")?;
+    check(module.clone(), "large", 100.0)?;
+    check(module.clone(), "small", 2.0)?;
+
+    let ast = typescript_ast::parser::file("tests/enums.ts").map_err(|e| e.to_string())?;
+
+    match &ast.statements[1] {
+        Statement::Enum(e) => {
+            assert_eq!(e.doc.as_deref(), Some("Named colors."));
+            assert_eq!(e.members[2].name, "Light Blue");
+            assert!(!e.is_const);
+        }
+        s => return Err(format!("expected an enum, got {:?}", s)),
+    }
+
+    match &ast.statements[3] {
+        Statement::Enum(e) => assert!(e.is_const),
+        s => return Err(format!("expected an enum, got {:?}", s)),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+enum Direction {
+    Up,
+    Down,
+    Left = 10,
+    Right,
+}
+
+/** Named colors. */
+enum Color {
+    Red = "r",
+    Green = "g",
+    "Light Blue" = "lb",
+}
+
+enum Flags {
+    None = 0,
+    Read = 1 << 0,
+    Write = 1 << 1,
+    Both = Read | Write,
+    Negative = -1,
+}
+
+const enum Size {
+    Small = 1,
+    Large = Small * 100,
+}
+
+const up = Direction.Up
+const right = Direction.Right
+const leftName = Direction[10]
+const downName = Direction[Direction.Down]
+const green = Color.Green
+const lightBlue = Color["Light Blue"]
+const both = Flags.Both
+const negativeName = Flags[-1]
+const large = Size.Large
+const small = Size.Small + 1
@@ -0,0 +1,72 @@
+use typescript_ast::ast::{
+    module::{ImportAlias, Reexport},
+    statement::Statement,
+    value::Value,
+};
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+#[test]
+fn run_exports() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/exports.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/exports.ts",
+        Some("results/exports.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "answer", 42.0)?;
+
+    match module.export("default").as_deref() {
+        Some(typescript_jit::Value::Number(n)) => assert_eq!(*n, 84.0),
+        v => return Err(format!("expected the default export, got {:?}", v)),
+    }
+
+    match module.export("seven").as_deref() {
+        Some(typescript_jit::Value::Number(n)) => assert_eq!(*n, 7.0),
+        v => return Err(format!("expected the renamed export, got {:?}", v)),
+    }
+
+    assert!(module.export("greet").is_some());
+    assert!(module.export("missing").is_none());
+
+    let ast = typescript_ast::parser::file("tests/exports.ts").map_err(|e| e.to_string())?;
+
+    let mut names: Vec<_> = ast.exports.keys().map(String::as_str).collect();
+    names.sort();
+    assert_eq!(
+        names,
+        vec!["Counter", "Level", "answer", "default", "first", "greet", "internal", "second", "seven"]
+    );
+
+    match ast.exports.get("seven") {
+        Some(Value::Identifier(path)) => assert_eq!(path, &vec!["renamed".to_string()]),
+        v => return Err(format!("expected an identifier, got {:?}", v)),
+    }
+
+    match &ast.statements[0] {
+        Statement::Function(f) => assert_eq!(f.doc.as_deref(), Some("Creates a greeting.")),
+        s => return Err(format!("expected a function, got {:?}", s)),
+    }
+
+    assert_eq!(ast.reexports.len(), 3);
+    match &ast.reexports[1] {
+        Reexport::All { alias, path } => {
+            assert_eq!(alias.as_deref(), Some("helpers"));
+            assert_eq!(path, "./helpers");
+        }
+        r => return Err(format!("expected a namespace re-export, got {:?}", r)),
+    }
+    match &ast.reexports[2] {
+        Reexport::Names { names, .. } => assert!(matches!(&names[1], ImportAlias::Alias { .. })),
+        r => return Err(format!("expected named re-exports, got {:?}", r)),
+    }
+
+    let factory = typescript_ast::parser::file("../samples/stanFactory.ts").map_err(|e| e.to_string())?;
+    assert!(factory.exports.contains_key("stan") && factory.exports.contains_key("stoo"));
+
+    Ok(())
+}
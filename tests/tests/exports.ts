@@ -0,0 +1,30 @@
+/** Creates a greeting. */
+export function greet(name: string) {
+    return "hello " + name
+}
+
+export const answer = 42
+export let [first, second] = [1, 2]
+
+export class Counter {
+    count = 3
+}
+
+export enum Level {
+    Low,
+    High,
+}
+
+export interface Options {
+    verbose: boolean
+}
+
+const internal = "kept"
+const renamed = 7
+
+export { internal, renamed as seven }
+export * from "./shared"
+export * as helpers from "./helpers"
+export { a, b as c } from "./letters"
+
+export default answer * 2
@@ -0,0 +1,33 @@
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check, check_boolean, check_string};
+
+#[test]
+fn run_expressions() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/expressions.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/expressions.ts",
+        Some("results/expressions.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "chain", 3.0)?;
+    check(module.clone(), "grouped", 9.0)?;
+    check(module.clone(), "power", 512.0)?;
+    check(module.clone(), "mixed", 2.0)?;
+    check(module.clone(), "bits", 9.0)?;
+    check(module.clone(), "shifts", 4.0)?;
+    check_boolean(module.clone(), "compare", true)?;
+    // the right side only runs if the left one doesn't decide the result
+    check_boolean(module.clone(), "skipped", false)?;
+    check_boolean(module.clone(), "taken", true)?;
+    check_boolean(module.clone(), "evaluated", true)?;
+    check(module.clone(), "calls", 1.0)?;
+    check_string(module.clone(), "fallback", "dflt")?;
+    check_string(module.clone(), "kept", "first")?;
+    check(module.clone(), "last", 2.0)?;
+
+    Ok(())
+}
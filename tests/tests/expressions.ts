@@ -0,0 +1,27 @@
+
+const a = 1
+const b = 2
+const c = 3
+const d = 4
+
+const chain = a + b * c - d
+const grouped = (a + b) * c
+const power = b ** c ** b
+const mixed = a + b * c % d / b
+const bits = 5 & 3 | 8 ^ 1
+const shifts = 1 << 4 >> 2
+const compare = a + b < c * d && d > c
+
+let calls = 0
+function sideEffect() {
+    calls = calls + 1
+    return true
+}
+const skipped = false && sideEffect()
+const taken = true || sideEffect()
+const evaluated = true && sideEffect()
+const fallback = null || "dflt"
+const kept = "first" || "second"
+const last = 1 && 2
+const missing = null
+const guarded = missing && missing.x
@@ -1,19 +1,25 @@
-use std::error::Error;
-
 use typescript_jit::Runtime;
-use typescript_tests::TestLogger;
+use typescript_tests::{TestLogger, check, check_string};
 
 #[test]
-fn run_functions() -> Result<(), Box<dyn Error>> {
+fn run_functions() -> Result<(), String> {
     log::set_boxed_logger(TestLogger::new("results/functions.log")).unwrap();
     log::set_max_level(log::LevelFilter::Trace);
 
     let rt = Runtime::new();
 
-    rt.load_file(
+    let module = rt.load_file(
         "tests/functions.ts",
         Some("results/functions.ir".into())
-    )?;
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "one", 3.0)?;
+    check(module.clone(), "fibonacci", 55.0)?;
+    // parameters do not overwrite variables of the same name outside the function
+    check(module.clone(), "n", 5.0)?;
+    check(module.clone(), "doubled", 6.0)?;
+    check_string(module.clone(), "negative", "negative")?;
+    check_string(module.clone(), "zero", "zero")?;
 
     Ok(())
-}
\ No newline at end of file
+}
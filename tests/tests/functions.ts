@@ -1,4 +1,31 @@
 
 function normalOne(one: number): number {
     return one + 2
-}
\ No newline at end of file
+}
+const one = normalOne(1)
+
+function fib(n: number): number {
+    if (n < 2) {
+        return n
+    }
+    return fib(n - 1) + fib(n - 2)
+}
+const fibonacci = fib(10)
+
+const n = 5
+function double(n: number): number {
+    return n * 2
+}
+const doubled = double(3)
+
+function sign(x: number): string {
+    if (x < 0) {
+        return "negative"
+    }
+    if (x > 0) {
+        return "positive"
+    }
+    return "zero"
+}
+const negative = sign(-2)
+const zero = sign(0)
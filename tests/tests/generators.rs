@@ -0,0 +1,35 @@
+use typescript_ast::ast::statement::Statement;
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check, check_string};
+
+#[test]
+fn run_generators() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/generators.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/generators.ts",
+        Some("results/generators.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "sum", 10.0)?;
+    check_string(module.clone(), "first", "a")?;
+    check_string(module.clone(), "second", "b")?;
+    check_string(module.clone(), "finished", "done")?;
+    check_string(module.clone(), "joined", "123ab")?;
+    check(module.clone(), "total", 30.0)?;
+
+    let ast = typescript_ast::parser::file("tests/generators.ts").map_err(|e| e.to_string())?;
+
+    for stmnt in &ast.statements {
+        match stmnt {
+            Statement::Function(f) => assert!(f.is_generator),
+            Statement::Class(c) => assert!(c.methods[0].function.is_generator),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+function* count(n: number) {
+    for (let i = 0; i < n; i++) {
+        yield i
+    }
+}
+
+let sum = 0
+for (const value of count(5)) {
+    sum += value
+}
+
+function* letters() {
+    yield "a"
+    yield "b"
+}
+
+const it = letters()
+const first = it.next().value
+const second = it.next().value
+const finished = it.next().done ? "done" : "running"
+
+function* all() {
+    yield 1
+    yield* [2, 3]
+    yield* letters()
+}
+
+let joined = ""
+for (const part of all()) {
+    joined = joined + part
+}
+
+class Range {
+    *values() {
+        yield 10
+        yield 20
+    }
+}
+
+let total = 0
+for (const step of new Range().values()) {
+    total += step
+}
@@ -0,0 +1,108 @@
+use typescript_ast::ast::{
+    module::{Import, ImportAlias},
+    statement::Statement,
+    value::Value,
+};
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+fn alias(import: &ImportAlias) -> (&str, &str) {
+    match import {
+        ImportAlias::None { name } => (name, name),
+        ImportAlias::Alias { name, alias } => (name, alias),
+    }
+}
+
+#[test]
+fn run_imports() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/imports.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/imports.ts",
+        Some("results/imports.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "value", 1.0)?;
+
+    let ast = typescript_ast::parser::file("tests/imports.ts").map_err(|e| e.to_string())?;
+    let imports = &ast.imports;
+    assert_eq!(imports.len(), 10);
+
+    assert!(matches!(&imports[0], Import::Normal { path } if path == "./setup"));
+
+    match &imports[1] {
+        Import::From { default: None, namespace: None, names, .. } => {
+            assert_eq!(alias(&names[1]), ("b", "c"));
+        }
+        i => return Err(format!("unexpected import {:?}", i)),
+    }
+
+    match &imports[2] {
+        Import::From { default, namespace: None, names, .. } => {
+            assert_eq!(default.as_deref(), Some("defaultValue"));
+            assert!(names.is_empty());
+        }
+        i => return Err(format!("unexpected import {:?}", i)),
+    }
+
+    match &imports[3] {
+        Import::From { default: None, namespace, .. } => {
+            assert_eq!(namespace.as_deref(), Some("utils"));
+        }
+        i => return Err(format!("unexpected import {:?}", i)),
+    }
+
+    match &imports[4] {
+        Import::From { default, names, .. } => {
+            assert_eq!(default.as_deref(), Some("main"));
+            assert_eq!(alias(&names[0]), ("helper", "helper"));
+        }
+        i => return Err(format!("unexpected import {:?}", i)),
+    }
+
+    match &imports[5] {
+        Import::From { default, namespace, .. } => {
+            assert_eq!(default.as_deref(), Some("main2"));
+            assert_eq!(namespace.as_deref(), Some("everything"));
+        }
+        i => return Err(format!("unexpected import {:?}", i)),
+    }
+
+    match &imports[6] {
+        Import::Type { names, path } => {
+            assert_eq!(alias(&names[0]), ("Shape", "Shape"));
+            assert_eq!(path, "./shapes");
+        }
+        i => return Err(format!("unexpected import {:?}", i)),
+    }
+
+    match &imports[7] {
+        Import::Type { names, .. } => assert_eq!(alias(&names[0]), ("default", "Config")),
+        i => return Err(format!("unexpected import {:?}", i)),
+    }
+
+    match (&imports[8], &imports[9]) {
+        (Import::Type { names: types, .. }, Import::From { names, .. }) => {
+            assert_eq!(alias(&types[0]), ("Options", "Options"));
+            assert_eq!(alias(&names[0]), ("parse", "parse"));
+            assert_eq!(alias(&names[1]), ("type", "kind"));
+        }
+        i => return Err(format!("unexpected imports {:?}", i)),
+    }
+
+    match &ast.statements[0] {
+        Statement::Function(f) => match &f.block[0] {
+            Statement::Const(declarators) => match declarators[0].value.as_deref().unwrap() {
+                Value::Await(v) => assert!(matches!(&**v, Value::DynamicImport(_))),
+                v => return Err(format!("expected await, got {:?}", v)),
+            },
+            s => return Err(format!("expected a const, got {:?}", s)),
+        },
+        s => return Err(format!("expected a function, got {:?}", s)),
+    }
+
+    Ok(())
+}
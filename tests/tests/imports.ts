@@ -0,0 +1,17 @@
+import "./setup"
+import { a, b as c } from "./letters"
+import defaultValue from "./default"
+import * as utils from "./utils"
+import main, { helper } from "./main"
+import main2, * as everything from "./main2"
+import type { Shape } from "./shapes"
+import type Config from "./config"
+import { type Options, parse, type as kind } from "./options"
+
+async function load() {
+    const module = await import("./lazy")
+    return module
+}
+
+const loaded = load()
+const value = 1
@@ -0,0 +1,51 @@
+use typescript_ast::ast::{repeat::Loop, statement::Statement};
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+#[test]
+fn run_jumps() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/jumps.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/jumps.ts",
+        Some("results/jumps.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "whileCount", 5.0)?;
+    check(module.clone(), "doCount", 11.0)?;
+    check(module.clone(), "evenSum", 20.0)?;
+    check(module.clone(), "firstBig", 8.0)?;
+    check(module.clone(), "whileSkipped", 18.0)?;
+    check(module.clone(), "doContinued", 8.0)?;
+    check(module.clone(), "ofSum", 8.0)?;
+    check(module.clone(), "pairs", 6.0)?;
+    check(module.clone(), "switched", 312.0)?;
+    check(module.clone(), "blockValue", 2.0)?;
+    check(module.clone(), "meal", 8.0)?;
+
+    let ast = typescript_ast::parser::file("tests/jumps.ts").map_err(|e| e.to_string())?;
+    let statements = &ast.statements;
+
+    assert!(matches!(&statements[1], Statement::Loop(Loop::While { .. })));
+    assert!(matches!(&statements[3], Statement::Loop(Loop::DoWhile { .. })));
+
+    match statements.iter().find(|s| matches!(s, Statement::Labeled { .. })) {
+        Some(Statement::Labeled { label, statement }) => {
+            assert_eq!(label, "outer");
+            assert!(matches!(**statement, Statement::Loop(Loop::For { .. })));
+        }
+        _ => return Err("the labeled loop is missing".into()),
+    }
+
+    // jumps without a matching loop are refused instead of compiled to nothing
+    let outside = rt.load_file("tests/jumps_outside.ts", None).map(|_| ()).unwrap_err();
+    assert_eq!(outside.to_string(), "continue outside of a loop");
+
+    let label = rt.load_file("tests/jumps_label.ts", None).map(|_| ()).unwrap_err();
+    assert_eq!(label.to_string(), "break to an unknown label: outer");
+
+    Ok(())
+}
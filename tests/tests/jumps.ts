@@ -0,0 +1,99 @@
+let whileCount = 0;
+while (whileCount < 5) {
+    whileCount++;
+}
+
+let doCount = 10;
+do {
+    doCount++;
+} while (doCount < 5)
+
+let evenSum = 0;
+for (let i = 0; i < 10; i++) {
+    if (i % 2 == 1) {
+        continue;
+    }
+    evenSum += i;
+}
+
+let firstBig = 0;
+for (let i = 0; i < 100; i++) {
+    if (i * i > 50) {
+        firstBig = i;
+        break;
+    }
+}
+
+let whileSkipped = 0;
+let whileStep = 0;
+while (true) {
+    whileStep++;
+    if (whileStep > 6) {
+        break;
+    }
+    if (whileStep == 3) {
+        continue;
+    }
+    whileSkipped += whileStep;
+}
+
+let doContinued = 0;
+let doStep = 0;
+do {
+    doStep++;
+    if (doStep == 2) {
+        continue;
+    }
+    doContinued += doStep;
+} while (doStep < 4)
+
+const items = [1, 2, 3, 4, 5];
+let ofSum = 0;
+for (const item of items) {
+    if (item == 2) {
+        continue;
+    }
+    if (item == 5) {
+        break;
+    }
+    ofSum += item;
+}
+
+let pairs = 0;
+outer: for (let a = 0; a < 5; a++) {
+    for (let b = 0; b < 5; b++) {
+        if (b > a) {
+            continue outer;
+        }
+        if (a == 3) {
+            break outer;
+        }
+        pairs++;
+    }
+}
+
+let switched = 0;
+for (let s = 0; s < 4; s++) {
+    switch (s) {
+        case 1:
+            switched += 10;
+            break;
+        case 2:
+            continue;
+        default:
+            switched += 1;
+    }
+    switched += 100;
+}
+
+let blockValue = 1;
+block: {
+    blockValue = 2;
+    if (blockValue == 2) {
+        break block;
+    }
+    blockValue = 3;
+}
+
+const breakfast = 7;
+const meal = breakfast + 1;
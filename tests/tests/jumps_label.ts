@@ -0,0 +1,6 @@
+let count = 0
+
+inner: while (count < 3) {
+    count++
+    break outer
+}
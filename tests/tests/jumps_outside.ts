@@ -0,0 +1,9 @@
+let count = 0
+
+while (count < 3) {
+    count++
+}
+
+function skip() {
+    continue
+}
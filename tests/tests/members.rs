@@ -0,0 +1,32 @@
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+#[test]
+fn run_members() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/members.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/members.ts",
+        Some("results/members.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "first", 10.0)?;
+    check(module.clone(), "last", 30.0)?;
+    check(module.clone(), "field", 1.0)?;
+    check(module.clone(), "nested", 3.0)?;
+    check(module.clone(), "grouped", 2.0)?;
+    check(module.clone(), "called", 42.0)?;
+    check(module.clone(), "chained", 2.0)?;
+    check(module.clone(), "computed", 5.0)?;
+    check(module.clone(), "written", 21.0)?;
+    check(module.clone(), "compound", 11.0)?;
+    check(module.clone(), "grown", 4.0)?;
+    check(module.clone(), "memberWrite", 6.0)?;
+    check(module.clone(), "increment", 2.0)?;
+    check(module.clone(), "deep", 9.0)?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+function makePoint() {
+    return { x: 1, y: 2, inner: { z: 3 } }
+}
+
+function double(n: number) {
+    return n * 2
+}
+
+const arr = [10, 20, 30]
+const first = arr[0]
+const last = arr[arr.length - 1]
+const field = makePoint().x
+const nested = makePoint().inner.z
+const grouped = (makePoint()).y
+
+const fns = [double]
+const called = fns[0](21)
+
+const obj = {
+    value: 5,
+    self() {
+        return obj
+    },
+    get(n: number) {
+        return n + 1
+    },
+}
+const chained = obj.self().self().get(1)
+const computed = obj["value"]
+
+arr[1] = 21
+const written = arr[1]
+arr[0] += 1
+const compound = arr[0]
+arr[3] = 40
+const grown = arr.length
+
+obj.self().value = 6
+const memberWrite = obj.value
+
+let counter = 0
+const counts = [0]
+counts[0]++
+counter++
+const increment = counts[0] + counter
+
+const point = makePoint()
+point.inner.z = 9
+const deep = point.inner.z
@@ -0,0 +1,48 @@
+use typescript_ast::ast::statement::Statement;
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+#[test]
+fn run_namespaces() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/namespaces.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/namespaces.ts",
+        Some("results/namespaces.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "unit", 1.0)?;
+    check(module.clone(), "doubled", 42.0)?;
+    check(module.clone(), "squareArea", 9.0)?;
+    check(module.clone(), "slow", 1.0)?;
+    check(module.clone(), "depth", 2.0)?;
+
+    let ast = typescript_ast::parser::file("tests/namespaces.ts").map_err(|e| e.to_string())?;
+
+    match &ast.statements[0] {
+        Statement::Namespace(n) => {
+            assert_eq!(n.name, "Geometry");
+            assert_eq!(n.exports, vec!["unit", "double", "Shape"]);
+            assert_eq!(n.statements.len(), 4);
+        }
+        s => return Err(format!("expected a namespace, got {:?}", s)),
+    }
+
+    match &ast.statements[1] {
+        Statement::Namespace(n) => {
+            assert_eq!(n.doc.as_deref(), Some("Nested through a dotted name."));
+            assert_eq!(n.exports, vec!["Shapes"]);
+
+            match &n.statements[0] {
+                Statement::Namespace(inner) => assert_eq!(inner.exports, vec!["Square"]),
+                s => return Err(format!("expected a namespace, got {:?}", s)),
+            }
+        }
+        s => return Err(format!("expected a namespace, got {:?}", s)),
+    }
+
+    Ok(())
+}
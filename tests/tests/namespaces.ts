@@ -0,0 +1,42 @@
+namespace Geometry {
+    export const unit = 1
+    const hidden = 42
+
+    export function double(n: number) {
+        return n * 2
+    }
+
+    export interface Shape {
+        area: number
+    }
+}
+
+/** Nested through a dotted name. */
+namespace Geometry.Shapes {
+    export class Square {
+        constructor(size: number) {
+            this.size = size
+        }
+
+        area() {
+            return this.size * this.size
+        }
+    }
+}
+
+module Legacy {
+    export enum Mode {
+        Fast,
+        Slow,
+    }
+
+    export namespace Inner {
+        export const depth = 2
+    }
+}
+
+const unit = Geometry.unit
+const doubled = Geometry.double(21)
+const squareArea = new Geometry.Shapes.Square(3).area()
+const slow = Legacy.Mode.Slow
+const depth = Legacy.Inner.depth
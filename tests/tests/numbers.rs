@@ -0,0 +1,37 @@
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+#[test]
+fn run_numbers() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/numbers.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/numbers.ts",
+        Some("results/numbers.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "integer", 42.0)?;
+    check(module.clone(), "fraction", 3.25)?;
+    check(module.clone(), "leading", 0.5)?;
+    check(module.clone(), "trailing", 5.0)?;
+    check(module.clone(), "exponent", 1000.0)?;
+    check(module.clone(), "negativeExponent", 0.25)?;
+    check(module.clone(), "separators", 1_000_000.0)?;
+    check(module.clone(), "hex", 255.0)?;
+    check(module.clone(), "octal", 15.0)?;
+    check(module.clone(), "binary", 10.0)?;
+    check(module.clone(), "hexSeparators", 65535.0)?;
+    check(module.clone(), "bigint", 123.0)?;
+    check(module.clone(), "hexBigint", 4294967296.0)?;
+
+    for malformed in ["const a = 1.2.3", "const a = 1__0", "const a = 0x", "const a = 12abc", "const a = 1_"] {
+        if typescript_ast::parser::source(malformed).is_ok() {
+            return Err(format!("{} should not parse", malformed));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+const integer = 42
+const fraction = 3.25
+const leading = .5
+const trailing = 5.
+const exponent = 1e3
+const negativeExponent = 25e-2
+const separators = 1_000_000
+const hex = 0xff
+const octal = 0o17
+const binary = 0b1010
+const hexSeparators = 0xFF_FF
+const bigint = 123n
+const hexBigint = 0x1_0000_0000n
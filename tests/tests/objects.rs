@@ -0,0 +1,32 @@
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+#[test]
+fn run_objects() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/objects.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/objects.ts",
+        Some("results/objects.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "a", 1.0)?;
+    check(module.clone(), "short", 2.0)?;
+    check(module.clone(), "computed", 3.0)?;
+    check(module.clone(), "quoted", 4.0)?;
+    check(module.clone(), "spread", 10.0)?;
+    check(module.clone(), "overwritten", 12.0)?;
+    check(module.clone(), "answer", 42.0)?;
+    check(module.clone(), "written", 7.0)?;
+    check(module.clone(), "updated", 5.0)?;
+    check(module.clone(), "fresh", 5.0)?;
+    // objects are shared, not copied
+    check(module.clone(), "aliased", 8.0)?;
+    check(module.clone(), "deleted", 1.0)?;
+    check(module.clone(), "same", 1.0)?;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+
+const key = "dyn"
+const b = 2
+const base = { x: 10, y: 11 }
+const empty = {}
+
+const obj = {
+    a: 1,
+    b,
+    [key]: 3,
+    "quoted": 4,
+    ...base,
+    y: 12,
+    answer() {
+        return 42
+    },
+}
+
+const a = obj.a
+const short = obj.b
+const computed = obj.dyn
+const quoted = obj.quoted
+const spread = obj.x
+const overwritten = obj.y
+const answer = obj.answer()
+
+obj.a = 7
+obj.b += 3
+empty.fresh = 5
+
+const written = obj.a
+const updated = obj.b
+const fresh = empty.fresh
+
+const alias = obj
+alias.a = 8
+delete alias.quoted
+
+const aliased = obj.a
+const deleted = "quoted" in obj ? 0 : 1
+const same = alias === obj ? 1 : 0
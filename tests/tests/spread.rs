@@ -0,0 +1,35 @@
+use typescript_ast::ast::statement::Statement;
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check, check_string};
+
+#[test]
+fn run_spread() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/spread.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/spread.ts",
+        Some("results/spread.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "spreadCall", 10.0)?;
+    check(module.clone(), "restCount", 4.0)?;
+    check(module.clone(), "noRest", 5.0)?;
+    check(module.clone(), "joinedLength", 6.0)?;
+    check(module.clone(), "joinedLast", 6.0)?;
+    check(module.clone(), "generatorLength", 2.0)?;
+    check_string(module.clone(), "generatorFirst", "a")?;
+    check(module.clone(), "derivedSum", 42.0)?;
+    check(module.clone(), "methodSpread", 5.0)?;
+
+    let ast = typescript_ast::parser::file("tests/spread.ts").map_err(|e| e.to_string())?;
+
+    match &ast.statements[0] {
+        Statement::Function(f) => assert!(!f.params[0].is_rest && f.params[1].is_rest),
+        s => return Err(format!("expected a function, got {:?}", s)),
+    }
+
+    Ok(())
+}
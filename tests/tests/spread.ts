@@ -0,0 +1,41 @@
+function total(first, ...others) {
+    let sum = first
+    for (const n of others) {
+        sum += n
+    }
+    return sum
+}
+
+const numbers = [2, 3, 4]
+const spreadCall = total(1, ...numbers)
+const restCount = ((...all) => all.length)(1, 2, 3, 4)
+const noRest = total(5)
+
+const joined = [0, ...numbers, ...[5, 6]]
+const joinedLength = joined.length
+const joinedLast = joined[5]
+
+function* pair() {
+    yield "a"
+    yield "b"
+}
+
+const fromGenerator = [...pair()]
+const generatorLength = fromGenerator.length
+const generatorFirst = fromGenerator[0]
+
+class Base {
+    constructor(a, b) {
+        this.sum = a + b
+    }
+}
+
+class Derived extends Base {}
+
+const derivedSum = new Derived(...[20, 22]).sum
+const methods = {
+    add(a, b) {
+        return a + b
+    },
+}
+const methodSpread = methods.add(...numbers)
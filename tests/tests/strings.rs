@@ -0,0 +1,32 @@
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check_string};
+
+#[test]
+fn run_strings() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/strings.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/strings.ts",
+        Some("results/strings.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check_string(module.clone(), "double", "hello, world!")?;
+    check_string(module.clone(), "single", "single \"quoted\"")?;
+    check_string(module.clone(), "quotes", "it's \"quoted\"")?;
+    check_string(module.clone(), "escapes", "a\tb\\c")?;
+    check_string(module.clone(), "hex", "AB")?;
+    check_string(module.clone(), "unicode", "A\u{1F600}")?;
+    check_string(module.clone(), "surrogate", "\u{1F600}")?;
+    check_string(module.clone(), "lone", "a\u{FFFD}b")?;
+    check_string(module.clone(), "continued", "line continued")?;
+    check_string(module.clone(), "template", "tab\there")?;
+    check_string(module.clone(), "nul", "a\0b")?;
+    check_string(module.clone(), "nul_hex", "\0")?;
+    check_string(module.clone(), "nul_unicode", "\0")?;
+    check_string(module.clone(), "nul_template", "x\0y")?;
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+const double = "hello, world!"
+const single = 'single "quoted"'
+const quotes = "it's \"quoted\""
+const escapes = "a\tb\\c"
+const hex = "\x41\x42"
+const unicode = "A\u{1F600}"
+const surrogate = "\uD83D\uDE00"
+const lone = "a\uD83Db"
+const continued = "line \
+continued"
+const template = `tab\there`
+const nul = "a\0b"
+const nul_hex = "\x00"
+const nul_unicode = "\u0000"
+const nul_template = `x\0y`
@@ -0,0 +1,27 @@
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check_string};
+
+#[test]
+fn run_templates() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/templates.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/templates.ts",
+        Some("results/templates.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check_string(module.clone(), "plain", "no substitution")?;
+    check_string(module.clone(), "empty", "")?;
+    check_string(module.clone(), "greeting", "hello dave!")?;
+    check_string(module.clone(), "numbers", "3 + 0.5 = 3.5")?;
+    check_string(module.clone(), "mixed", "false 1,2,3 [object Object]")?;
+    check_string(module.clone(), "nested", "outer inner dave")?;
+    check_string(module.clone(), "concat", "count 3")?;
+    // the strings around the substitutions come first, then the substituted values
+    check_string(module.clone(), "tagged", "a|b||3|3|dave")?;
+
+    Ok(())
+}
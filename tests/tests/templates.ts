@@ -0,0 +1,20 @@
+
+const name = "dave"
+const count = 3
+const half = 0.5
+const done = false
+const list = [1, 2, 3]
+const tags = {
+    tag(strings, first, second) {
+        return strings[0] + "|" + strings[1] + "|" + strings[2] + "|" + strings.length + "|" + first + "|" + second
+    },
+}
+
+const plain = `no substitution`
+const empty = ``
+const greeting = `hello ${name}!`
+const numbers = `${count} + ${half} = ${count + half}`
+const mixed = `${done} ${list} ${{}}`
+const nested = `outer ${`inner ${name}`}`
+const concat = "count " + count
+const tagged = tags.tag`a${count}b${name}`
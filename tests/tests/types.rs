@@ -0,0 +1,89 @@
+use typescript_ast::ast::{
+    statement::Statement,
+    tstype::{TsType, TypeMember},
+};
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+fn type_alias<'a>(statements: &'a [Statement], name: &str) -> &'a TsType {
+    statements
+        .iter()
+        .find_map(|s| match s {
+            Statement::Type(t) if t.name == name => Some(&t.kind),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn run_types() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/types.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/types.ts",
+        Some("results/types.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "doubled", 42.0)?;
+    check(module.clone(), "textCheck", 1.0)?;
+    check(module.clone(), "sum", 4.0)?;
+
+    let ast = typescript_ast::parser::file("tests/types.ts").map_err(|e| e.to_string())?;
+    let statements = &ast.statements;
+
+    assert!(matches!(type_alias(statements, "Id"), TsType::Union(kinds) if kinds.len() == 2));
+    assert!(matches!(type_alias(statements, "Pair"), TsType::Tuple(elements) if elements[1].is_optional));
+    assert!(matches!(
+        type_alias(statements, "Callback"),
+        TsType::Function { params, returns, .. } if params[1].is_optional && matches!(**returns, TsType::Void)
+    ));
+    match type_alias(statements, "Point") {
+        TsType::TypeLiteral(members) => {
+            assert!(matches!(&members[0], TypeMember::Property { is_readonly: true, .. }));
+            assert!(matches!(&members[2], TypeMember::Index { key: TsType::String, .. }));
+        }
+        t => return Err(format!("expected a type literal, got {:?}", t)),
+    }
+    assert!(matches!(type_alias(statements, "Level"), TsType::Union(kinds) if matches!(kinds[0], TsType::Literal(_))));
+    assert!(matches!(type_alias(statements, "Unwrap"), TsType::Conditional { .. }));
+    assert!(matches!(type_alias(statements, "Optional"), TsType::Mapped { .. }));
+
+    match statements.iter().find(|s| matches!(s, Statement::Interface(i) if i.name == "NamedHolder")) {
+        Some(Statement::Interface(i)) => {
+            assert_eq!(i.extends.len(), 2);
+            assert!(matches!(&i.extends[0], TsType::Reference { name, args } if name == "Named" && args.is_empty()));
+            assert!(matches!(&i.extends[1], TsType::Reference { args, .. } if matches!(args[..], [TsType::Number])));
+        }
+        _ => return Err("NamedHolder is missing".into()),
+    }
+
+    match statements.iter().find(|s| matches!(s, Statement::Class(c) if c.name == "Box")) {
+        Some(Statement::Class(c)) => {
+            assert!(c.extends.is_none());
+            assert!(matches!(&c.implements[..], [TsType::Reference { name, args }] if name == "Holder" && args.len() == 1));
+            assert!(matches!(c.attributes[0].kind, Some(TsType::Reference { .. })));
+        }
+        _ => return Err("Box is missing".into()),
+    }
+
+    match statements.iter().find(|s| matches!(s, Statement::Function(f) if f.name.as_deref() == Some("identity"))) {
+        Some(Statement::Function(f)) => {
+            assert_eq!(f.template_args.len(), 2);
+            assert_eq!(f.template_args[1].name, "U");
+            assert!(f.template_args[1].default.is_some());
+        }
+        _ => return Err("identity is missing".into()),
+    }
+
+    match statements.iter().find(|s| matches!(s, Statement::Function(f) if f.name.as_deref() == Some("isText"))) {
+        Some(Statement::Function(f)) => {
+            assert!(matches!(&f.returns, Some(TsType::Predicate { name, .. }) if name == "candidate"));
+        }
+        _ => return Err("isText is missing".into()),
+    }
+
+    Ok(())
+}
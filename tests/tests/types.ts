@@ -0,0 +1,39 @@
+type Id = string | number;
+type Pair<A, B = A> = [first: A, second?: B];
+type Callback = (error: Error | null, value?: string) => void;
+type Point = { readonly x: number; y?: number; [key: string]: unknown };
+type Level = "info" | "warn";
+type Unwrap<T> = T extends Promise<infer U> ? U : T;
+type Optional<T> = { [K in keyof T]?: T[K] };
+
+interface Holder<T> {
+    value: T
+}
+
+interface Named {
+    name: string
+}
+
+interface NamedHolder extends Named, Holder<number> {}
+
+class Box<T> implements Holder<T> {
+    value: T;
+    constructor(value: T) {
+        this.value = value;
+    }
+}
+
+function identity<T, U = T>(input: T): T {
+    return input
+}
+
+function isText(candidate: unknown): candidate is string {
+    return typeof candidate === "string"
+}
+
+const numbers: number[] = [1, 2, 3];
+const table: Map<string, Array<number>> | null = null;
+const boxed = new Box<number>(21);
+const doubled = identity<number>(boxed.value) * 2;
+const textCheck = isText("text") ? 1 : 0;
+const sum = numbers[0] + numbers[2];
@@ -0,0 +1,43 @@
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check, check_boolean, check_string};
+
+#[test]
+fn run_unary() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/unary.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/unary.ts",
+        Some("results/unary.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "negative", -5.0)?;
+    check(module.clone(), "negated", 6.0)?;
+    check(module.clone(), "positive", 3.0)?;
+    check(module.clone(), "inverted", -6.0)?;
+    check(module.clone(), "notDone", 1.0)?;
+    check(module.clone(), "isString", 1.0)?;
+    check(module.clone(), "isNumber", 1.0)?;
+    check_string(module.clone(), "undefinedType", "undefined")?;
+    check_string(module.clone(), "voidType", "undefined")?;
+    check_string(module.clone(), "nullType", "object")?;
+    // a hoisted `var` is `undefined` until it gets assigned
+    check_string(module.clone(), "hoistedType", "undefined")?;
+    check(module.clone(), "looseNull", 1.0)?;
+    check(module.clone(), "strictNull", 0.0)?;
+    check(module.clone(), "negativeCase", 2.0)?;
+    check(module.clone(), "offsetDefault", 4.0)?;
+    check_boolean(module.clone(), "notEmpty", true)?;
+    check_boolean(module.clone(), "notNaN", true)?;
+    check(module.clone(), "emptyPicked", 2.0)?;
+    check(module.clone(), "emptyIf", 0.0)?;
+    // unresolved names are `undefined`
+    check_boolean(module.clone(), "notUnknown", true)?;
+    check_boolean(module.clone(), "negativeIsNaN", true)?;
+    check_boolean(module.clone(), "positiveIsNaN", true)?;
+    check(module.clone(), "invertedUnknown", -1.0)?;
+
+    Ok(())
+}
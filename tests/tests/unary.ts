@@ -0,0 +1,62 @@
+
+const done = false
+const name = "dave"
+let notDone = 0
+let isString = 0
+let isNumber = 0
+
+const negative = -5
+const negated = -negative + 1
+const positive = +"3"
+const inverted = ~5
+const nothing = void 0
+
+if (!done) {
+    notDone = 1
+}
+
+if (typeof name === "string") {
+    isString = 1
+}
+
+if (typeof negative !== "string") {
+    isNumber = 1
+}
+
+const undefinedType = typeof undefined
+const voidType = typeof nothing
+const nullType = typeof null
+const hoistedType = typeof later
+var later = 1
+const looseNull = null == undefined ? 1 : 0
+const strictNull = null === undefined ? 1 : 0
+
+let negativeCase = 0
+switch (negative + 4) {
+    case 1:
+        negativeCase = 1
+        break
+    case -1:
+        negativeCase = 2
+        break
+}
+
+function offset(x, by = -1) {
+    return x + by
+}
+const offsetDefault = offset(5)
+
+const notEmpty = !""
+const notNaN = !(0 / 0)
+const emptyPicked = "" ? 1 : 2
+let emptyIf = 0
+if ("") {
+    emptyIf = 1
+}
+
+const notUnknown = !unknownName
+const negativeUnknown = -unknownName
+const positiveUnknown = +unknownName
+const negativeIsNaN = negativeUnknown !== negativeUnknown
+const positiveIsNaN = positiveUnknown !== positiveUnknown
+const invertedUnknown = ~unknownName
@@ -0,0 +1,79 @@
+use typescript_ast::ast::{pattern::Pattern, repeat::Loop, statement::Statement, tstype::TsType};
+use typescript_jit::Runtime;
+use typescript_tests::{TestLogger, check};
+
+#[test]
+fn run_variables() -> Result<(), String> {
+    log::set_boxed_logger(TestLogger::new("results/variables.log")).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let rt = Runtime::new();
+
+    let module = rt.load_file(
+        "tests/variables.ts",
+        Some("results/variables.ir".into())
+    ).map_err(|e| e.to_string())?;
+
+    check(module.clone(), "sum", 6.0)?;
+    check(module.clone(), "emptyIsUndefined", 1.0)?;
+    check(module.clone(), "empty", 4.0)?;
+    check(module.clone(), "hoisted", 1.0)?;
+    check(module.clone(), "assignedEarly", 5.0)?;
+    check(module.clone(), "y", 2.0)?;
+    check(module.clone(), "counted", 64.0)?;
+    check(module.clone(), "shadowedValue", 8.0)?;
+    // a `var` in a function belongs to that function and leaves the module `x` alone
+    check(module.clone(), "localX", 2.0)?;
+    check(module.clone(), "x", 1.0)?;
+    check(module.clone(), "letter", 6.0)?;
+    check(module.clone(), "declared", 6.0)?;
+    // `let` and `const` only live in their block, every loop iteration gets its own
+    check(module.clone(), "outerKept", 1.0)?;
+    check(module.clone(), "fromBlock", 2.0)?;
+    check(module.clone(), "capturedFirst", 0.0)?;
+    check(module.clone(), "capturedSecond", 1.0)?;
+    check(module.clone(), "capturedOf", 5.0)?;
+    check(module.clone(), "left", 33.0)?;
+
+    let ast = typescript_ast::parser::file("tests/variables.ts").map_err(|e| e.to_string())?;
+    let statements = &ast.statements;
+
+    match &statements[0] {
+        Statement::Let(declarators) => {
+            assert_eq!(declarators.len(), 3);
+            assert!(declarators[0].kind.is_none());
+            assert!(matches!(declarators[2].kind, Some(TsType::Number)));
+        }
+        s => return Err(format!("expected a let, got {:?}", s)),
+    }
+
+    match &statements[2] {
+        Statement::Let(declarators) => assert!(declarators[0].value.is_none()),
+        s => return Err(format!("expected a let, got {:?}", s)),
+    }
+
+    match statements.iter().find(|s| matches!(s, Statement::Var(d) if d.len() == 2)) {
+        Some(Statement::Var(declarators)) => {
+            assert!(matches!(&declarators[1].pattern, Pattern::Name(name) if name == "y"));
+            assert!(declarators[1].value.is_none());
+        }
+        _ => return Err("the var with two declarators is missing".into()),
+    }
+
+    match statements.iter().rev().find(|s| matches!(s, Statement::Let(_))) {
+        Some(Statement::Let(declarators)) => {
+            assert!(matches!(&declarators[0].kind, Some(TsType::Union(kinds)) if kinds.len() == 2));
+        }
+        _ => return Err("the declared let is missing".into()),
+    }
+
+    match statements.iter().find(|s| matches!(s, Statement::Function(_))) {
+        Some(Statement::Function(f)) => {
+            assert_eq!(typescript_ast::ast::variable::var_names(&f.block), ["i", "total", "last"]);
+            assert!(matches!(&f.block[0], Statement::Loop(Loop::For { .. })));
+        }
+        _ => return Err("the function is missing".into()),
+    }
+
+    Ok(())
+}
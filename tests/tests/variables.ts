@@ -0,0 +1,93 @@
+let a = 1, b = 2, c: number = 3;
+let sum = a + b + c;
+
+let empty;
+let emptyIsUndefined = empty === undefined ? 1 : 0;
+empty = 4;
+
+let hoisted = early === undefined ? 1 : 0;
+var early = 5;
+let assignedEarly = early;
+
+var x = 1, y: number;
+y = x + 1;
+
+function count(n: number): number {
+    for (var i = 0, total = 0; i < n; i++) {
+        total += i;
+    }
+    if (n > 0) {
+        var last = i;
+    }
+    return total * 10 + last;
+}
+
+let counted = count(4);
+
+function shadowed(): number {
+    let seen = inner === undefined ? 1 : 0;
+    {
+        var inner = 7;
+    }
+    return seen + inner;
+}
+
+let shadowedValue = shadowed();
+
+function local(): number {
+    var x = 2;
+    return x;
+}
+
+let localX = local();
+
+let outer = 1;
+{
+    let outer = 2;
+    var fromBlock = outer;
+}
+let outerKept = outer;
+
+let firstI, secondI;
+for (let i = 0; i < 2; i++) {
+    if (i === 0) {
+        firstI = () => i;
+    } else {
+        secondI = () => i;
+    }
+}
+let capturedFirst = firstI();
+let capturedSecond = secondI();
+
+let firstOf;
+for (const v of [5, 6]) {
+    if (v === 5) {
+        firstOf = () => v;
+    }
+}
+let capturedOf = firstOf();
+
+function leave(): number {
+    let n = 0;
+    while (true) {
+        const next = n + 1;
+        n = next;
+        if (next > 2) {
+            break;
+        }
+    }
+    for (let i = 0; i < 5; i++) {
+        const twice = i * 2;
+        if (twice > 4) {
+            return i * 10 + n;
+        }
+    }
+    return -1;
+}
+
+let left = leave();
+
+let variable = 2;
+let letter = variable * 3;
+
+let declared: string | number = 6;